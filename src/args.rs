@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{ops::RangeInclusive, path::PathBuf};
 
 use clap::Parser;
 
@@ -16,4 +16,121 @@ pub struct Args {
 
     #[arg(short, long, default_value = "./rustedex-dev")]
     pub path: PathBuf,
+
+    /// URL prefix of every generated link, such as / to host the site at a domain root.
+    /// Use . to generate relative links, so the site can be opened from the file system.
+    #[arg(short, long, default_value = "/rustedex/")]
+    pub base_url: String,
+
+    /// Directory of templates and partials overriding the default ones, such as partials/card.hbs.
+    #[arg(short, long)]
+    pub templates: Option<PathBuf>,
+
+    /// If set, the previous output is kept and only pages whose data or templates changed are rendered again.
+    #[arg(short, long)]
+    pub incremental: bool,
+
+    /// If set, every generated Pokemon is also exported as JSON, along with an index.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Maximum number of Pokemon pages generated concurrently.
+    #[arg(short, long, default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..))]
+    pub jobs: u16,
+
+    /// Languages of the generated Pokedex, as PokeAPI language ids (en, fr, de, ja, ...).
+    /// Each language is generated in its own subdirectory.
+    /// PokeAPI wordings are localized, while the labels of the site itself stay in English.
+    #[arg(short, long, value_delimiter = ',', default_value = "en")]
+    pub lang: Vec<String>,
+
+    /// Languages to fall back on, by order of preference, when a wording is missing in the requested language.
+    #[arg(long, value_delimiter = ',')]
+    pub fallback_lang: Vec<String>,
+
+    /// Directory where PokeAPI responses are cached between runs.
+    #[arg(long, default_value = "./rustedex-cache")]
+    pub cache_dir: PathBuf,
+
+    /// Time to live of a cached PokeAPI response, in seconds. Cached responses never expire if unset.
+    #[arg(long)]
+    pub cache_ttl: Option<u64>,
+
+    /// If set, Rustedex only reads from the cache and never reaches PokeAPI.
+    #[arg(long)]
+    pub offline: bool,
+
+    #[command(flatten)]
+    pub selection: Selection,
+}
+
+/// Filters on the Pokemons to generate. Every Pokemon is generated if none is set.
+#[derive(clap::Args, Debug)]
+pub struct Selection {
+    /// Names of the Pokemons to generate, such as bulbasaur,pikachu.
+    #[arg(long = "pokemon", value_delimiter = ',')]
+    pub names: Vec<String>,
+
+    /// Range of national Pokedex numbers of the Pokemons to generate, such as 1-151.
+    #[arg(long, value_parser = parse_range)]
+    pub range: Option<RangeInclusive<i64>>,
+
+    /// Generations the Pokemons to generate were introduced in, such as 1,2.
+    #[arg(long = "generation", value_delimiter = ',')]
+    pub generations: Vec<i64>,
+
+    /// Types of the Pokemons to generate, such as fire,water.
+    #[arg(long = "type", value_delimiter = ',')]
+    pub types: Vec<String>,
+
+    /// If set, alternate forms, regional variants and Megas are not generated.
+    #[arg(long)]
+    pub default_forms_only: bool,
+}
+
+fn parse_range(range: &str) -> Result<RangeInclusive<i64>, String> {
+    let parse_bound = |bound: &str| {
+        bound
+            .trim()
+            .parse::<i64>()
+            .map_err(|e| format!("Invalid bound {:?}: {}", bound, e))
+    };
+
+    let (start, end) = match range.split_once('-') {
+        Some((start, end)) => (parse_bound(start)?, parse_bound(end)?),
+        None => {
+            let number = parse_bound(range)?;
+            (number, number)
+        }
+    };
+
+    if start > end {
+        return Err(format!(
+            "Empty range {:?}: {} is after {}",
+            range, start, end
+        ));
+    }
+
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_range_reads_bounds() {
+        assert_eq!(parse_range("1-151"), Ok(1..=151));
+        assert_eq!(parse_range(" 152 - 251 "), Ok(152..=251));
+        assert_eq!(parse_range("25"), Ok(25..=25));
+    }
+
+    #[test]
+    fn parse_range_rejects_invalid_ranges() {
+        assert!(parse_range("").is_err());
+        assert!(parse_range("one-151").is_err());
+        assert!(parse_range("1-").is_err());
+        assert!(parse_range("1-151-251").is_err());
+        assert!(parse_range("151-1").is_err());
+    }
 }
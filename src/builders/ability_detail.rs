@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rustemon::client::RustemonClient;
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The Pokemon having an ability, normally or as their hidden ability.
+#[derive(Default)]
+pub(crate) struct AbilityHolders {
+    ability_id: String,
+    pokemon: Vec<PokemonElement>,
+    hidden_pokemon: Vec<PokemonElement>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct AbilityDetail {
+    ability_id: String,
+    name: String,
+    effect: Option<String>,
+    flavor_texts: Vec<(&'static str, String)>,
+    pokemon: Vec<PokemonElement>,
+    hidden_pokemon: Vec<PokemonElement>,
+}
+
+impl Element for AbilityHolders {
+    fn element_id(&self) -> &str {
+        &self.ability_id
+    }
+}
+
+/// Gathers, for every ability of the generated Pokemon, which of them have it.
+pub(crate) fn collect_ability_holders(
+    generated_pokemons: &[GeneratedPokemon],
+) -> Vec<AbilityHolders> {
+    let mut holders_by_ability: BTreeMap<String, AbilityHolders> = BTreeMap::new();
+
+    for generated_pokemon in generated_pokemons {
+        for pokemon_ability in &generated_pokemon.pokemon().abilities {
+            let ability_holders = holders_by_ability
+                .entry(pokemon_ability.ability.name.clone())
+                .or_insert_with(|| AbilityHolders {
+                    ability_id: pokemon_ability.ability.name.clone(),
+                    ..Default::default()
+                });
+
+            let pokemon_element = generated_pokemon.element().clone();
+            if pokemon_ability.is_hidden {
+                ability_holders.hidden_pokemon.push(pokemon_element);
+            } else {
+                ability_holders.pokemon.push(pokemon_element);
+            }
+        }
+    }
+
+    holders_by_ability.into_values().collect()
+}
+
+#[async_trait]
+impl Builder<AbilityHolders> for AbilityDetail {
+    async fn build(
+        ability_holders: &AbilityHolders,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let ability_id = &ability_holders.ability_id;
+        let ability = rustemon::pokemon::ability::get_by_name(ability_id, rc).await?;
+
+        let name = ability
+            .names
+            .find_by_lang(lang)
+            .with_context(|| format!("No {} name for ability {:?}", lang, ability_id))?;
+
+        let effect = ability.effect_entries.find_by_lang(lang);
+
+        let flavor_texts = utils::get_flavor_texts_by_version_group(
+            &ability.flavor_text_entries,
+            |flavor_text_entry| {
+                (
+                    &flavor_text_entry.language.name,
+                    &flavor_text_entry.version_group.name,
+                    &flavor_text_entry.flavor_text,
+                )
+            },
+            lang,
+        );
+
+        let mut pokemon = ability_holders.pokemon.clone();
+        pokemon.sort_by_key(PokemonElement::id);
+        let mut hidden_pokemon = ability_holders.hidden_pokemon.clone();
+        hidden_pokemon.sort_by_key(PokemonElement::id);
+
+        Ok(Self {
+            ability_id: ability_id.clone(),
+            name,
+            effect,
+            flavor_texts,
+            pokemon,
+            hidden_pokemon,
+        })
+    }
+}
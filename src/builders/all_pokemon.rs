@@ -1,16 +1,21 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
-use rustemon::{client::RustemonClient, Follow};
+use rustemon::{
+    client::RustemonClient,
+    model::pokemon::{Pokemon, PokemonSpecies},
+    Follow,
+};
 use serde::Serialize;
 
 use super::Builder;
-use crate::find_by_lang::FindWordingByLang;
+use crate::{find_by_lang::Lang, utils};
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub(crate) struct PokemonElement {
     id: i64,
+    pokemon_id: String,
     display_name: String,
     sprite: String,
     path: PathBuf,
@@ -21,46 +26,108 @@ pub(crate) struct AllPokemon {
     pokemon_elements: Vec<PokemonElement>,
 }
 
-#[async_trait]
-impl Builder<Vec<(String, PathBuf)>> for AllPokemon {
-    async fn build(data: &Vec<(String, PathBuf)>, rc: &RustemonClient, lang: &str) -> Result<Self> {
-        let mut pokemon_elements = Vec::with_capacity(data.len());
+/// A Pokemon whose page was generated, with its PokeAPI resources,
+/// fetched once for all the pages listing the generated Pokemon.
+pub(crate) struct GeneratedPokemon {
+    pokemon: Pokemon,
+    species: PokemonSpecies,
+    element: PokemonElement,
+}
+
+impl PokemonElement {
+    async fn build(
+        pokemon: &Pokemon,
+        pokemon_species: &PokemonSpecies,
+        path: &Path,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let pokemon_index = pokemon_species
+            .pokedex_numbers
+            .iter()
+            .find(|pokemon_number| pokemon_number.pokedex.name == "national")
+            .map(|pokemon_number| pokemon_number.entry_number)
+            .unwrap_or(9999);
+
+        let display_name =
+            utils::get_pokemon_name_by_lang(pokemon, pokemon_species, lang, rc).await?;
+
+        let pokemon_sprite = pokemon.sprites.front_default.clone().unwrap_or_else(|| {
+            format!(
+                "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/{}.png",
+                pokemon.id
+            )
+        });
+
+        Ok(Self {
+            id: pokemon_index,
+            pokemon_id: pokemon.name.clone(),
+            display_name,
+            sprite: pokemon_sprite,
+            path: path.to_owned(),
+        })
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn sprite(&self) -> &str {
+        &self.sprite
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
 
-        for (pokemon_id, path) in data {
-            let pokemon = rustemon::pokemon::pokemon::get_by_name(pokemon_id, rc).await?;
+impl GeneratedPokemon {
+    pub async fn build(
+        pokemon_id: &str,
+        path: &Path,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let pokemon = rustemon::pokemon::pokemon::get_by_name(pokemon_id, rc).await?;
+        let species = pokemon.species.follow(rc).await?;
+        let element = PokemonElement::build(&pokemon, &species, path, rc, lang).await?;
 
-            let pokemon_species = pokemon.species.follow(rc).await?;
+        Ok(Self {
+            pokemon,
+            species,
+            element,
+        })
+    }
+
+    pub fn pokemon(&self) -> &Pokemon {
+        &self.pokemon
+    }
 
-            let pokemon_index = pokemon_species
-                .pokedex_numbers
+    pub fn species(&self) -> &PokemonSpecies {
+        &self.species
+    }
+
+    pub fn element(&self) -> &PokemonElement {
+        &self.element
+    }
+}
+
+#[async_trait]
+impl Builder<Vec<GeneratedPokemon>> for AllPokemon {
+    async fn build(
+        generated_pokemons: &Vec<GeneratedPokemon>,
+        _rc: &RustemonClient,
+        _lang: &Lang,
+    ) -> Result<Self> {
+        Ok(Self {
+            pokemon_elements: generated_pokemons
                 .iter()
-                .find(|pokemon_number| pokemon_number.pokedex.name == "national")
-                .map(|pokemon_number| pokemon_number.entry_number)
-                .unwrap_or(9999);
-
-            let names = pokemon_species.names;
-            let display_name = names
-                .find_by_lang(lang)
-                .with_context(|| format!("No {} name for {}", lang, pokemon_id))?;
-
-            let pokemon_sprite = pokemon
-                .sprites
-                .front_default
-                .unwrap_or_else(|| {
-                    format!(
-                        "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/{}.png",
-                        pokemon.id
-                    )
-                });
-
-            pokemon_elements.push(PokemonElement {
-                id: pokemon_index,
-                display_name,
-                sprite: pokemon_sprite,
-                path: path.clone(),
-            });
-        }
-
-        Ok(Self { pokemon_elements })
+                .map(|generated_pokemon| generated_pokemon.element.clone())
+                .collect(),
+        })
     }
 }
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::{
+    client::RustemonClient,
+    model::{pokemon::PokemonSpecies, resource::Name},
+    Follow,
+};
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    Builder, Element,
+};
+use crate::find_by_lang::{FindWordingByLang, Lang};
+
+/// The browsable categories, by identifier, in their display order.
+/// Their names are labels of the site, in English like the templates, in every language.
+const CATEGORIES: [(&str, &str); 5] = [
+    ("generations", "Generations"),
+    ("statuses", "Legendary, mythical and baby"),
+    ("habitats", "Habitats"),
+    ("colors", "Colors"),
+    ("shapes", "Shapes"),
+];
+
+/// The statuses a species can have, with their display name.
+/// PokeAPI has no localized names for them, so they stay in English in every language,
+/// like the category names, while the other attributes are named after PokeAPI resources.
+const STATUSES: [(&str, &str); 3] = [
+    ("legendary", "Legendary"),
+    ("mythical", "Mythical"),
+    ("baby", "Baby"),
+];
+
+/// A value of a browsable category, such as a generation or a color, linking to its page.
+#[derive(Serialize, Clone)]
+pub(crate) struct Attribute {
+    attribute_id: String,
+    name: String,
+    #[serde(skip)]
+    order: i64,
+}
+
+/// The browsable attributes of a Pokemon species.
+#[derive(Serialize)]
+pub(crate) struct SpeciesAttributes {
+    generation: Attribute,
+    habitat: Option<Attribute>,
+    color: Attribute,
+    shape: Option<Attribute>,
+    statuses: Vec<Attribute>,
+}
+
+/// The generated Pokemon sharing an attribute.
+pub(crate) struct BrowseMembers {
+    page_id: String,
+    category_id: &'static str,
+    attribute: Attribute,
+    members: Vec<BrowseMember>,
+}
+
+/// The page of the generated Pokemon sharing an attribute.
+#[derive(Serialize)]
+pub(crate) struct BrowseGroup {
+    category_id: &'static str,
+    category_name: &'static str,
+    attribute: Attribute,
+    members: Vec<BrowseMember>,
+}
+
+#[derive(Serialize, Clone)]
+pub(crate) struct BrowseMember {
+    pokemon: PokemonElement,
+    statuses: Vec<Attribute>,
+}
+
+/// The index of the browse pages, by category.
+#[derive(Serialize)]
+pub(crate) struct Browse {
+    categories: Vec<BrowseCategory>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct BrowseCategory {
+    category_id: &'static str,
+    name: &'static str,
+    groups: Vec<GroupLink>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct GroupLink {
+    attribute: Attribute,
+    count: usize,
+}
+
+impl SpeciesAttributes {
+    pub async fn build(
+        pokemon_species: &PokemonSpecies,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let generation = pokemon_species.generation.follow(rc).await?;
+        let color = pokemon_species.color.follow(rc).await?;
+
+        let habitat = match &pokemon_species.habitat {
+            Some(habitat) => {
+                let habitat = habitat.follow(rc).await?;
+                Some(attribute(habitat.id, habitat.name, habitat.names, lang))
+            }
+            None => None,
+        };
+
+        let shape = match &pokemon_species.shape {
+            Some(shape) => {
+                let shape = shape.follow(rc).await?;
+                Some(attribute(shape.id, shape.name, shape.names, lang))
+            }
+            None => None,
+        };
+
+        let flags = [
+            pokemon_species.is_legendary,
+            pokemon_species.is_mythical,
+            pokemon_species.is_baby,
+        ];
+        let statuses = STATUSES
+            .iter()
+            .zip(flags)
+            .enumerate()
+            .filter(|(_, (_, flag))| *flag)
+            .map(|(order, ((attribute_id, name), _))| Attribute {
+                attribute_id: attribute_id.to_string(),
+                name: name.to_string(),
+                order: order as i64,
+            })
+            .collect();
+
+        Ok(Self {
+            generation: attribute(generation.id, generation.name, generation.names, lang),
+            habitat,
+            color: attribute(color.id, color.name, color.names, lang),
+            shape,
+            statuses,
+        })
+    }
+
+    /// Returns the attributes of every category, along with the category identifier.
+    fn by_category(&self) -> Vec<(&'static str, &Attribute)> {
+        let mut attributes = vec![("generations", &self.generation), ("colors", &self.color)];
+        attributes.extend(self.habitat.iter().map(|habitat| ("habitats", habitat)));
+        attributes.extend(self.shape.iter().map(|shape| ("shapes", shape)));
+        attributes.extend(self.statuses.iter().map(|status| ("statuses", status)));
+        attributes
+    }
+}
+
+/// Returns the attribute named after `names`, or after its identifier if none matches `lang`.
+fn attribute(order: i64, attribute_id: String, names: Vec<Name>, lang: &Lang) -> Attribute {
+    Attribute {
+        name: names
+            .find_by_lang(lang)
+            .unwrap_or_else(|| attribute_id.clone()),
+        attribute_id,
+        order,
+    }
+}
+
+impl Element for BrowseMembers {
+    /// Returns the path of the group page, relative to the browse directory.
+    fn element_id(&self) -> &str {
+        &self.page_id
+    }
+}
+
+/// Groups the generated Pokemon by the value of every browsable category.
+pub(crate) async fn collect_browse_members(
+    generated_pokemons: &[GeneratedPokemon],
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<Vec<BrowseMembers>> {
+    let mut members_by_attribute: BTreeMap<_, (Attribute, Vec<BrowseMember>)> = BTreeMap::new();
+
+    for generated_pokemon in generated_pokemons {
+        let species_attributes =
+            SpeciesAttributes::build(generated_pokemon.species(), rc, lang).await?;
+
+        for (category_id, attribute) in species_attributes.by_category() {
+            members_by_attribute
+                .entry((category_id, attribute.order, attribute.attribute_id.clone()))
+                .or_insert_with(|| (attribute.clone(), Vec::new()))
+                .1
+                .push(BrowseMember {
+                    pokemon: generated_pokemon.element().clone(),
+                    statuses: species_attributes.statuses.clone(),
+                });
+        }
+    }
+
+    Ok(members_by_attribute
+        .into_iter()
+        .map(
+            |((category_id, _, attribute_id), (attribute, members))| BrowseMembers {
+                page_id: format!("{}/{}", category_id, attribute_id),
+                category_id,
+                attribute,
+                members,
+            },
+        )
+        .collect())
+}
+
+#[async_trait]
+impl Builder<BrowseMembers> for BrowseGroup {
+    async fn build(
+        browse_members: &BrowseMembers,
+        _rc: &RustemonClient,
+        _lang: &Lang,
+    ) -> Result<Self> {
+        let mut members = browse_members.members.clone();
+        members.sort_by_key(|member| member.pokemon.id());
+
+        Ok(Self {
+            category_id: browse_members.category_id,
+            category_name: category_name(browse_members.category_id),
+            attribute: browse_members.attribute.clone(),
+            members,
+        })
+    }
+}
+
+fn category_name(category_id: &str) -> &'static str {
+    CATEGORIES
+        .iter()
+        .find(|(id, _)| *id == category_id)
+        .map(|(_, name)| *name)
+        .unwrap_or_default()
+}
+
+impl Browse {
+    pub fn new(browse_members: &[BrowseMembers]) -> Self {
+        let categories = CATEGORIES
+            .iter()
+            .map(|(category_id, name)| BrowseCategory {
+                category_id,
+                name,
+                groups: browse_members
+                    .iter()
+                    .filter(|browse_members| browse_members.category_id == *category_id)
+                    .map(|browse_members| GroupLink {
+                        attribute: browse_members.attribute.clone(),
+                        count: browse_members.members.len(),
+                    })
+                    .collect(),
+            })
+            .filter(|browse_category| !browse_category.groups.is_empty())
+            .collect();
+
+        Self { categories }
+    }
+}
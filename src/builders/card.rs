@@ -1,19 +1,25 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use rustemon::{
     client::RustemonClient,
-    model::resource::{Name, NamedApiResource},
+    model::{
+        pokemon::PokemonAbility,
+        resource::{Name, NamedApiResource},
+    },
     Follow,
 };
 use serde::Serialize;
 
-use super::Builder;
+use super::{
+    browse::SpeciesAttributes, pokedex_detail::NATIONAL_POKEDEX, pokemon::SelectedPokemon, Builder,
+};
 use crate::{
-    find_by_lang::FindWordingByLang,
+    find_by_lang::{FindWordingByLang, Lang},
     utils::{
-        get_abilities_names_by_lang, get_effort_points_map_by_lang, get_egg_groups_names_by_lang,
+        get_effort_points_map_by_lang, get_egg_groups_names_by_lang, get_name_by_lang,
+        get_pokemon_name_by_lang,
     },
 };
 
@@ -23,9 +29,39 @@ pub(crate) struct GenderRates {
     female: f32,
 }
 
+/// A variety of the Pokemon species, such as its regional or Mega forms.
+#[derive(Serialize)]
+pub(crate) struct Form {
+    pokemon_id: String,
+    name: String,
+    is_current: bool,
+    /// Whether the variety has a page to link to.
+    is_generated: bool,
+}
+
+/// An ability of the Pokemon, linking to its page.
+#[derive(Serialize)]
+pub(crate) struct Ability {
+    ability_id: String,
+    name: String,
+    is_hidden: bool,
+    slot: i64,
+}
+
+/// The number of the Pokemon in a Pokedex, linking to it unless it is the national one.
+#[derive(Serialize)]
+pub(crate) struct PokedexNumber {
+    pokedex_id: String,
+    name: String,
+    entry_number: i64,
+    is_national: bool,
+}
+
 #[derive(Serialize)]
 pub(crate) struct Card {
     artwork_url: String,
+    name: String,
+    forms: Vec<Form>,
     name_fr: String,
     name_en: String,
     name_jp: String,
@@ -33,24 +69,47 @@ pub(crate) struct Card {
     genus: String,
     height: f32,
     weight: f32,
-    abilities: Vec<String>,
+    abilities: Vec<Ability>,
     egg_groups: Vec<String>,
     steps_until_hatch: i64,
-    effort_points: HashMap<String, i64>,
+    effort_points: BTreeMap<String, i64>,
     base_experience: i64,
     lvl_100_experience: i64,
     gender_rates: Option<GenderRates>,
-    color: String,
+    attributes: SpeciesAttributes,
     capture_rate: i64,
+    pokedex_numbers: Vec<PokedexNumber>,
 }
 
 #[async_trait]
-impl Builder<String> for Card {
-    async fn build(id: &String, rc: &RustemonClient, lang: &str) -> Result<Self> {
-        let pokemon = rustemon::pokemon::pokemon::get_by_name(id, rc).await?;
+impl Builder<SelectedPokemon> for Card {
+    async fn build(
+        selected_pokemon: &SelectedPokemon,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let pokemon =
+            rustemon::pokemon::pokemon::get_by_name(selected_pokemon.pokemon_id(), rc).await?;
         let pokemon_specie = pokemon.species.follow(rc).await?;
         let growth_rate = pokemon_specie.growth_rate.follow(rc).await?;
-        let pokemon_color = pokemon_specie.color.follow(rc).await?;
+
+        let name = get_pokemon_name_by_lang(&pokemon, &pokemon_specie, lang, rc).await?;
+
+        let attributes = SpeciesAttributes::build(&pokemon_specie, rc, lang).await?;
+
+        let mut forms = Vec::new();
+        if pokemon_specie.varieties.len() > 1 {
+            for variety in &pokemon_specie.varieties {
+                let variety_pokemon = variety.pokemon.follow(rc).await?;
+                forms.push(Form {
+                    name: get_pokemon_name_by_lang(&variety_pokemon, &pokemon_specie, lang, rc)
+                        .await?,
+                    is_current: variety_pokemon.name == pokemon.name,
+                    is_generated: selected_pokemon.is_selected(&variety_pokemon.name),
+                    pokemon_id: variety_pokemon.name,
+                });
+            }
+        }
 
         let artwork_url = pokemon
             .sprites
@@ -93,7 +152,7 @@ impl Builder<String> for Card {
 
         let weight = pokemon.weight as f32 / 10.0;
 
-        let abilities = get_abilities_names_by_lang(pokemon.abilities, lang, rc).await?;
+        let abilities = build_abilities(pokemon.abilities, lang, rc).await?;
 
         let egg_groups = get_egg_groups_names_by_lang(pokemon_specie.egg_groups, lang, rc).await?;
 
@@ -121,15 +180,23 @@ impl Builder<String> for Card {
             })
         };
 
-        let color = pokemon_color
-            .names
-            .find_by_lang(lang)
-            .with_context(|| format!("No color in {} for {:?}", lang, pokemon_color.name))?;
-
         let capture_rate = pokemon_specie.capture_rate;
 
+        let mut pokedex_numbers = Vec::with_capacity(pokemon_specie.pokedex_numbers.len());
+        for pokedex_number in pokemon_specie.pokedex_numbers {
+            pokedex_numbers.push(PokedexNumber {
+                name: get_name_by_lang(&pokedex_number.pokedex, |pokedex| pokedex.names, rc, lang)
+                    .await?,
+                is_national: pokedex_number.pokedex.name == NATIONAL_POKEDEX,
+                pokedex_id: pokedex_number.pokedex.name,
+                entry_number: pokedex_number.entry_number,
+            });
+        }
+
         let card = Card {
             artwork_url,
+            name,
+            forms,
             name_fr,
             name_en,
             name_jp: format!("{} {}", name_ja, name_roomaji),
@@ -144,10 +211,44 @@ impl Builder<String> for Card {
             base_experience,
             lvl_100_experience,
             gender_rates,
-            color,
+            attributes,
             capture_rate,
+            pokedex_numbers,
         };
 
         Ok(card)
     }
 }
+
+async fn build_abilities(
+    mut pokemon_abilities: Vec<PokemonAbility>,
+    lang: &Lang,
+    rc: &RustemonClient,
+) -> Result<Vec<Ability>> {
+    pokemon_abilities.sort_by_key(|pokemon_ability| pokemon_ability.slot);
+
+    let mut abilities = Vec::with_capacity(pokemon_abilities.len());
+    for pokemon_ability in pokemon_abilities {
+        let name = pokemon_ability
+            .ability
+            .follow(rc)
+            .await?
+            .names
+            .find_by_lang(lang)
+            .with_context(|| {
+                format!(
+                    "No {} name for ability {}",
+                    lang, pokemon_ability.ability.name
+                )
+            })?;
+
+        abilities.push(Ability {
+            ability_id: pokemon_ability.ability.name,
+            name,
+            is_hidden: pokemon_ability.is_hidden,
+            slot: pokemon_ability.slot,
+        });
+    }
+
+    Ok(abilities)
+}
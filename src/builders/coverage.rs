@@ -0,0 +1,79 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, Follow};
+use serde::Serialize;
+
+use super::{
+    weaknesses::{self, DamageMultiplicator},
+    Builder,
+};
+use crate::{find_by_lang::Lang, utils};
+
+/// The best damage multiplicator a Pokemon can deal to each type,
+/// with its own types (STAB) only, and with all the damaging moves it can learn.
+#[derive(Serialize)]
+pub(crate) struct Coverage {
+    stab: Vec<(String, DamageMultiplicator)>,
+    moves: Vec<(String, DamageMultiplicator)>,
+    move_types: Vec<String>,
+}
+
+#[async_trait]
+impl Builder<String> for Coverage {
+    async fn build(id: &String, rc: &RustemonClient, _lang: &Lang) -> Result<Self> {
+        let pokemon = rustemon::pokemon::pokemon::get_by_name(id, rc).await?;
+
+        let stab_types: BTreeSet<_> = pokemon
+            .types
+            .iter()
+            .map(|pokemon_type| pokemon_type.type_.name.clone())
+            .collect();
+
+        let mut move_types = stab_types.clone();
+        for pokemon_move in &pokemon.moves {
+            let move_ = pokemon_move.move_.follow(rc).await?;
+            if move_.damage_class.name != "status" {
+                move_types.insert(move_.type_.name);
+            }
+        }
+
+        Ok(Self {
+            stab: best_damage_multiplicators(&stab_types, rc).await?,
+            moves: best_damage_multiplicators(&move_types, rc).await?,
+            move_types: utils::get_type_ids()
+                .into_iter()
+                .filter(|type_id| move_types.contains(type_id))
+                .collect(),
+        })
+    }
+}
+
+/// Returns, for each defending type, the best multiplicator among the `attacking_types`.
+async fn best_damage_multiplicators(
+    attacking_types: &BTreeSet<String>,
+    rc: &RustemonClient,
+) -> Result<Vec<(String, DamageMultiplicator)>> {
+    let mut best: Vec<_> = utils::get_type_ids()
+        .into_iter()
+        .map(|type_id| (type_id, DamageMultiplicator::Immune))
+        .collect();
+
+    for attacking_type in attacking_types {
+        let damage_relations = rustemon::pokemon::type_::get_by_name(attacking_type, rc)
+            .await?
+            .damage_relations;
+        let damage_multiplicators = weaknesses::damage_multiplicators(
+            &damage_relations.double_damage_to,
+            &damage_relations.half_damage_to,
+            &damage_relations.no_damage_to,
+        );
+
+        for ((_, best), (_, damage_multiplicator)) in best.iter_mut().zip(damage_multiplicators) {
+            *best = (*best).max(damage_multiplicator);
+        }
+    }
+
+    Ok(best)
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -9,14 +9,14 @@ use rustemon::{
 };
 use serde::Serialize;
 
-use crate::find_by_lang::FindWordingByLang;
+use crate::find_by_lang::{FindWordingByLang, Lang};
 
 use super::Builder;
 
 #[derive(Serialize)]
 pub(crate) struct Descriptions {
-    generation_id_to_names: HashMap<String, String>,
-    generation_id_to_version_name_and_flavor_text: HashMap<String, Vec<VersionNameWithFlavorText>>,
+    generation_id_to_names: BTreeMap<String, String>,
+    generation_id_to_version_name_and_flavor_text: BTreeMap<String, Vec<VersionNameWithFlavorText>>,
 }
 
 #[derive(Serialize)]
@@ -28,15 +28,15 @@ pub(crate) struct VersionNameWithFlavorText {
 impl Descriptions {
     fn new() -> Self {
         Self {
-            generation_id_to_names: HashMap::new(),
-            generation_id_to_version_name_and_flavor_text: HashMap::new(),
+            generation_id_to_names: BTreeMap::new(),
+            generation_id_to_version_name_and_flavor_text: BTreeMap::new(),
         }
     }
 }
 
 #[async_trait]
 impl Builder<String> for Descriptions {
-    async fn build(id: &String, rc: &RustemonClient, lang: &str) -> Result<Self> {
+    async fn build(id: &String, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
         let flavor_text_entries = rustemon::pokemon::pokemon::get_by_name(id, rc)
             .await?
             .species
@@ -44,6 +44,17 @@ impl Builder<String> for Descriptions {
             .await?
             .flavor_text_entries;
 
+        // Flavor texts are all taken from the first language of the chain having any.
+        let description_lang = lang
+            .ids()
+            .iter()
+            .find(|lang_id| {
+                flavor_text_entries
+                    .iter()
+                    .any(|flavor_text_entry| &flavor_text_entry.language.name == *lang_id)
+            })
+            .cloned();
+
         let mut descriptions = Descriptions::new();
 
         for flavor_text_entry in flavor_text_entries {
@@ -56,7 +67,7 @@ impl Builder<String> for Descriptions {
                     }),
             } = flavor_text_entry
             {
-                if *lang != language {
+                if description_lang.as_ref() != Some(&language) {
                     continue;
                 }
 
@@ -93,7 +104,7 @@ impl Builder<String> for Descriptions {
                 descriptions
                     .generation_id_to_version_name_and_flavor_text
                     .entry(generation_id)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(VersionNameWithFlavorText {
                         version_name,
                         flavor_text,
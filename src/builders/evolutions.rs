@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures::{future::BoxFuture, FutureExt};
+use rustemon::{
+    client::RustemonClient,
+    model::{
+        evolution::{ChainLink, EvolutionDetail},
+        resource::{Name, NamedApiResource},
+    },
+    Follow,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{pokemon::SelectedPokemon, Builder};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+#[derive(Serialize)]
+pub(crate) struct Evolutions {
+    chain: EvolutionStage,
+}
+
+/// A Pokemon of an evolution chain, with the methods to evolve into it from the previous stage.
+#[derive(Serialize)]
+pub(crate) struct EvolutionStage {
+    pokemon_id: String,
+    name: String,
+    sprite: String,
+    is_baby: bool,
+    is_current: bool,
+    /// Whether the Pokemon has a page to link to.
+    is_generated: bool,
+    methods: Vec<EvolutionMethod>,
+    evolves_to: Vec<EvolutionStage>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct EvolutionMethod {
+    trigger: String,
+    min_level: Option<i64>,
+    item: Option<String>,
+    held_item: Option<String>,
+    known_move: Option<String>,
+    known_move_type: Option<String>,
+    location: Option<String>,
+    min_happiness: Option<i64>,
+    min_beauty: Option<i64>,
+    min_affection: Option<i64>,
+    time_of_day: Option<String>,
+    gender: Option<&'static str>,
+    needs_overworld_rain: bool,
+    party_species: Option<String>,
+    party_type: Option<String>,
+    trade_species: Option<String>,
+    relative_physical_stats: Option<&'static str>,
+    turn_upside_down: bool,
+}
+
+#[async_trait]
+impl Builder<SelectedPokemon> for Evolutions {
+    async fn build(
+        selected_pokemon: &SelectedPokemon,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let chain = rustemon::pokemon::pokemon::get_by_name(selected_pokemon.pokemon_id(), rc)
+            .await?
+            .species
+            .follow(rc)
+            .await?
+            .evolution_chain
+            .follow(rc)
+            .await?
+            .chain;
+
+        Ok(Self {
+            chain: build_stage(&chain, selected_pokemon, rc, lang).await?,
+        })
+    }
+}
+
+fn build_stage<'a>(
+    chain_link: &'a ChainLink,
+    selected_pokemon: &'a SelectedPokemon,
+    rc: &'a RustemonClient,
+    lang: &'a Lang,
+) -> BoxFuture<'a, Result<EvolutionStage>> {
+    async move {
+        let pokemon_specie = chain_link.species.follow(rc).await?;
+        let name = pokemon_specie
+            .names
+            .find_by_lang(lang)
+            .with_context(|| format!("No {} name for {}", lang, pokemon_specie.name))?;
+
+        let pokemon = pokemon_specie
+            .varieties
+            .iter()
+            .find(|variety| variety.is_default)
+            .with_context(|| format!("No default variety for {}", pokemon_specie.name))?
+            .pokemon
+            .follow(rc)
+            .await?;
+        let sprite = pokemon.sprites.front_default.unwrap_or_else(|| {
+            format!(
+                "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/{}.png",
+                pokemon.id
+            )
+        });
+
+        let mut methods = Vec::with_capacity(chain_link.evolution_details.len());
+        for evolution_detail in &chain_link.evolution_details {
+            methods.push(build_method(evolution_detail, rc, lang).await?);
+        }
+
+        let mut evolves_to = Vec::with_capacity(chain_link.evolves_to.len());
+        for next_chain_link in &chain_link.evolves_to {
+            evolves_to.push(build_stage(next_chain_link, selected_pokemon, rc, lang).await?);
+        }
+
+        Ok(EvolutionStage {
+            is_current: &pokemon.name == selected_pokemon.pokemon_id(),
+            is_generated: selected_pokemon.is_selected(&pokemon.name),
+            pokemon_id: pokemon.name,
+            name,
+            sprite,
+            is_baby: chain_link.is_baby,
+            methods,
+            evolves_to,
+        })
+    }
+    .boxed()
+}
+
+async fn build_method(
+    evolution_detail: &EvolutionDetail,
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<EvolutionMethod> {
+    let trigger =
+        utils::get_name_by_lang(&evolution_detail.trigger, |trigger| trigger.names, rc, lang)
+            .await?;
+
+    Ok(EvolutionMethod {
+        trigger,
+        min_level: evolution_detail.min_level,
+        item: find_optional_name(&evolution_detail.item, |item| item.names, rc, lang).await?,
+        held_item: find_optional_name(&evolution_detail.held_item, |item| item.names, rc, lang)
+            .await?,
+        known_move: find_optional_name(&evolution_detail.known_move, |move_| move_.names, rc, lang)
+            .await?,
+        known_move_type: evolution_detail
+            .known_move_type
+            .as_ref()
+            .map(|type_| type_.name.clone()),
+        location: find_optional_name(
+            &evolution_detail.location,
+            |location| location.names,
+            rc,
+            lang,
+        )
+        .await?,
+        min_happiness: evolution_detail.min_happiness,
+        min_beauty: evolution_detail.min_beauty,
+        min_affection: evolution_detail.min_affection,
+        time_of_day: Some(evolution_detail.time_of_day.clone())
+            .filter(|time_of_day| !time_of_day.is_empty()),
+        gender: evolution_detail.gender.map(|gender| match gender {
+            1 => "female",
+            _ => "male",
+        }),
+        needs_overworld_rain: evolution_detail.needs_overworld_rain,
+        party_species: find_optional_name(
+            &evolution_detail.party_species,
+            |pokemon_specie| pokemon_specie.names,
+            rc,
+            lang,
+        )
+        .await?,
+        party_type: evolution_detail
+            .party_type
+            .as_ref()
+            .map(|type_| type_.name.clone()),
+        trade_species: find_optional_name(
+            &evolution_detail.trade_species,
+            |pokemon_specie| pokemon_specie.names,
+            rc,
+            lang,
+        )
+        .await?,
+        relative_physical_stats: evolution_detail.relative_physical_stats.map(
+            |relative_physical_stats| match relative_physical_stats {
+                1 => "Attack > Defense",
+                0 => "Attack = Defense",
+                _ => "Attack < Defense",
+            },
+        ),
+        turn_upside_down: evolution_detail.turn_upside_down,
+    })
+}
+
+async fn find_optional_name<T>(
+    resource: &Option<NamedApiResource<T>>,
+    names: impl FnOnce(T) -> Vec<Name>,
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<Option<String>>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    match resource {
+        Some(resource) => Ok(Some(
+            utils::get_name_by_lang(resource, names, rc, lang).await?,
+        )),
+        None => Ok(None),
+    }
+}
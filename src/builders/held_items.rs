@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, Follow};
+use serde::Serialize;
+
+use super::Builder;
+use crate::find_by_lang::{FindWordingByLang, Lang};
+
+/// The items the Pokemon can hold when encountered in the wild, by version.
+#[derive(Serialize)]
+pub(crate) struct HeldItems {
+    versions: Vec<VersionHeldItems>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct VersionHeldItems {
+    version_id: String,
+    version_name: String,
+    items: Vec<HeldItem>,
+}
+
+#[derive(Serialize, Clone)]
+pub(crate) struct HeldItem {
+    item_id: String,
+    name: String,
+    sprite: Option<String>,
+    rarity: i64,
+}
+
+#[async_trait]
+impl Builder<String> for HeldItems {
+    async fn build(id: &String, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let held_items = rustemon::pokemon::pokemon::get_by_name(id, rc)
+            .await?
+            .held_items;
+
+        let mut items_by_version: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        let mut version_names = BTreeMap::new();
+
+        for held_item in held_items {
+            let item = held_item.item.follow(rc).await?;
+            let item_name = item
+                .names
+                .find_by_lang(lang)
+                .unwrap_or_else(|| item.name.clone());
+
+            for version_detail in held_item.version_details {
+                let version = version_detail.version.follow(rc).await?;
+                let version_key = (version.id, version.name.clone());
+                version_names.insert(
+                    version_key.clone(),
+                    version.names.find_by_lang(lang).unwrap_or(version.name),
+                );
+
+                items_by_version
+                    .entry(version_key)
+                    .or_default()
+                    .push(HeldItem {
+                        item_id: item.name.clone(),
+                        name: item_name.clone(),
+                        sprite: item.sprites.default.clone(),
+                        rarity: version_detail.rarity,
+                    });
+            }
+        }
+
+        Ok(Self {
+            versions: items_by_version
+                .into_iter()
+                .map(|(version_key, items)| VersionHeldItems {
+                    version_name: version_names.remove(&version_key).unwrap_or_default(),
+                    version_id: version_key.1,
+                    items,
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::client::RustemonClient;
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The generated Pokemon holding an item in the wild.
+pub(crate) struct ItemHolders {
+    item_id: String,
+    holders: Vec<PokemonElement>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ItemDetail {
+    item_id: String,
+    name: String,
+    category: String,
+    effect: Option<String>,
+    cost: i64,
+    sprite: Option<String>,
+    holders: Vec<PokemonElement>,
+}
+
+impl Element for ItemHolders {
+    fn element_id(&self) -> &str {
+        &self.item_id
+    }
+}
+
+/// Gathers, for every item held by the generated Pokemon, which of them hold it.
+pub(crate) fn collect_item_holders(generated_pokemons: &[GeneratedPokemon]) -> Vec<ItemHolders> {
+    let mut holders_by_item: BTreeMap<String, Vec<PokemonElement>> = BTreeMap::new();
+
+    for generated_pokemon in generated_pokemons {
+        for held_item in &generated_pokemon.pokemon().held_items {
+            holders_by_item
+                .entry(held_item.item.name.clone())
+                .or_default()
+                .push(generated_pokemon.element().clone());
+        }
+    }
+
+    holders_by_item
+        .into_iter()
+        .map(|(item_id, holders)| ItemHolders { item_id, holders })
+        .collect()
+}
+
+#[async_trait]
+impl Builder<ItemHolders> for ItemDetail {
+    async fn build(item_holders: &ItemHolders, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let item_id = &item_holders.item_id;
+        let item = rustemon::items::item::get_by_name(item_id, rc).await?;
+
+        let name = item
+            .names
+            .find_by_lang(lang)
+            .unwrap_or_else(|| item.name.clone());
+
+        let category =
+            utils::get_name_by_lang(&item.category, |category| category.names, rc, lang).await?;
+
+        let mut holders = item_holders.holders.clone();
+        holders.sort_by_key(PokemonElement::id);
+
+        Ok(Self {
+            item_id: item_id.clone(),
+            name,
+            category,
+            effect: item.effect_entries.find_by_lang(lang),
+            cost: item.cost,
+            sprite: item.sprites.default,
+            holders,
+        })
+    }
+}
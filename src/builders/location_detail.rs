@@ -0,0 +1,208 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, model::resource::Encounter, Follow};
+use serde::Serialize;
+
+use super::{
+    all_pokemon::GeneratedPokemon,
+    locations::{self, EncounterDetail},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// A location where some generated Pokemon can be encountered.
+pub(crate) struct GeneratedLocation {
+    location_id: String,
+    generated_pokemon_ids: Arc<BTreeSet<String>>,
+}
+
+/// The wild Pokemon of a location, by version and area.
+#[derive(Serialize)]
+pub(crate) struct LocationDetail {
+    location_id: String,
+    name: String,
+    region_name: Option<String>,
+    versions: Vec<VersionAreas>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct VersionAreas {
+    version_id: String,
+    version_name: String,
+    areas: Vec<AreaPokemon>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct AreaPokemon {
+    name: String,
+    pokemon: Vec<WildPokemon>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct WildPokemon {
+    pokemon_id: String,
+    name: String,
+    sprite: Option<String>,
+    /// Whether the Pokemon has a page to link to.
+    is_generated: bool,
+    encounters: Vec<EncounterDetail>,
+}
+
+impl Element for GeneratedLocation {
+    fn element_id(&self) -> &str {
+        &self.location_id
+    }
+}
+
+/// Gathers the locations where the generated Pokemon can be encountered.
+pub(crate) async fn collect_generated_locations(
+    generated_pokemons: &[GeneratedPokemon],
+    rc: &RustemonClient,
+) -> Result<Vec<GeneratedLocation>> {
+    let generated_pokemon_ids: Arc<BTreeSet<_>> = Arc::new(
+        generated_pokemons
+            .iter()
+            .map(|generated_pokemon| generated_pokemon.pokemon().name.clone())
+            .collect(),
+    );
+
+    let mut location_ids = BTreeSet::new();
+    for generated_pokemon in generated_pokemons {
+        let pokemon_id = generated_pokemon.pokemon().id;
+        for location_area_encounter in
+            rustemon::pokemon::pokemon::encounters::get_by_id(pokemon_id, rc).await?
+        {
+            let location_area = location_area_encounter.location_area.follow(rc).await?;
+            location_ids.insert(location_area.location.name);
+        }
+    }
+
+    Ok(location_ids
+        .into_iter()
+        .map(|location_id| GeneratedLocation {
+            location_id,
+            generated_pokemon_ids: generated_pokemon_ids.clone(),
+        })
+        .collect())
+}
+
+#[async_trait]
+impl Builder<GeneratedLocation> for LocationDetail {
+    async fn build(
+        generated_location: &GeneratedLocation,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let location_id = &generated_location.location_id;
+        let location = rustemon::locations::location::get_by_name(location_id, rc).await?;
+
+        let name = location
+            .names
+            .find_by_lang(lang)
+            .unwrap_or_else(|| location.name.clone());
+        let region_name = match &location.region {
+            Some(region) => {
+                Some(utils::get_name_by_lang(region, |region| region.names, rc, lang).await?)
+            }
+            None => None,
+        };
+
+        // Raw encounters by Pokemon, by area, by version.
+        let mut encounters_by_version: BTreeMap<_, BTreeMap<_, BTreeMap<_, Vec<_>>>> =
+            BTreeMap::new();
+        let mut version_names = BTreeMap::new();
+
+        for area in &location.areas {
+            let location_area = area.follow(rc).await?;
+            let area_name = locations::get_area_name(&location_area, &location, lang);
+
+            for pokemon_encounter in location_area.pokemon_encounters {
+                let pokemon = pokemon_encounter.pokemon.follow(rc).await?;
+                let pokemon_key = (pokemon.id, pokemon.name);
+
+                for version_detail in pokemon_encounter.version_details {
+                    let version = version_detail.version.follow(rc).await?;
+                    let version_key = (version.id, version.name.clone());
+                    version_names.insert(
+                        version_key.clone(),
+                        version.names.find_by_lang(lang).unwrap_or(version.name),
+                    );
+
+                    encounters_by_version
+                        .entry(version_key)
+                        .or_default()
+                        .entry(area_name.clone())
+                        .or_default()
+                        .entry(pokemon_key.clone())
+                        .or_default()
+                        .extend(version_detail.encounter_details);
+                }
+            }
+        }
+
+        let mut versions = Vec::with_capacity(encounters_by_version.len());
+        for (version_key, encounters_by_area) in encounters_by_version {
+            let mut areas = Vec::with_capacity(encounters_by_area.len());
+            for (area_name, encounters_by_pokemon) in encounters_by_area {
+                let mut pokemon = Vec::with_capacity(encounters_by_pokemon.len());
+                for ((_, pokemon_id), encounters) in encounters_by_pokemon {
+                    pokemon.push(
+                        build_wild_pokemon(
+                            pokemon_id,
+                            &encounters,
+                            &generated_location.generated_pokemon_ids,
+                            rc,
+                            lang,
+                        )
+                        .await?,
+                    );
+                }
+
+                areas.push(AreaPokemon {
+                    name: area_name,
+                    pokemon,
+                });
+            }
+
+            versions.push(VersionAreas {
+                version_name: version_names.remove(&version_key).unwrap_or_default(),
+                version_id: version_key.1,
+                areas,
+            });
+        }
+
+        Ok(Self {
+            location_id: location_id.clone(),
+            name,
+            region_name,
+            versions,
+        })
+    }
+}
+
+async fn build_wild_pokemon(
+    pokemon_id: String,
+    encounters: &[Encounter],
+    generated_pokemon_ids: &BTreeSet<String>,
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<WildPokemon> {
+    let pokemon = rustemon::pokemon::pokemon::get_by_name(&pokemon_id, rc).await?;
+    let pokemon_specie = pokemon.species.follow(rc).await?;
+
+    Ok(WildPokemon {
+        name: utils::get_pokemon_name_by_lang(&pokemon, &pokemon_specie, lang, rc).await?,
+        sprite: pokemon.sprites.front_default,
+        is_generated: generated_pokemon_ids.contains(&pokemon_id),
+        encounters: locations::group_encounters(encounters, rc, lang).await?,
+        pokemon_id,
+    })
+}
@@ -1,64 +1,165 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::Result;
 use async_trait::async_trait;
-use rustemon::{client::RustemonClient, Follow};
+use rustemon::{
+    client::RustemonClient,
+    model::{
+        locations::{Location, LocationArea},
+        resource::Encounter,
+    },
+    Follow,
+};
 use serde::Serialize;
 
-use crate::{find_by_lang::FindWordingByLang, utils};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
 
 use super::Builder;
 
+/// Where the Pokemon can be encountered, by version.
 #[derive(Serialize)]
 pub(crate) struct Locations {
-    version_group_id_and_names: Vec<(&'static str, &'static str)>,
-    locations_by_version: HashMap<String, Vec<String>>,
+    versions: Vec<VersionLocations>,
 }
 
-/// TODO: Remove once data is present
-impl Default for Locations {
-    fn default() -> Self {
-        Self {
-            version_group_id_and_names: vec![],
-            locations_by_version: HashMap::new(),
-        }
-    }
+#[derive(Serialize)]
+pub(crate) struct VersionLocations {
+    version_id: String,
+    version_name: String,
+    areas: Vec<AreaEncounters>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct AreaEncounters {
+    location_id: String,
+    name: String,
+    encounters: Vec<EncounterDetail>,
+}
+
+/// The encounters of an area sharing the same method and conditions,
+/// with their level range and cumulated chance.
+#[derive(Serialize)]
+pub(crate) struct EncounterDetail {
+    method: String,
+    min_level: i64,
+    max_level: i64,
+    chance: i64,
+    conditions: Vec<String>,
 }
 
 #[async_trait]
 impl Builder<String> for Locations {
-    async fn build(id: &String, rc: &RustemonClient, lang: &str) -> Result<Self> {
+    async fn build(id: &String, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
         let pokemon_id = rustemon::pokemon::pokemon::get_by_name(id, rc).await?.id;
 
         let location_area_encounters =
             rustemon::pokemon::pokemon::encounters::get_by_id(pokemon_id, rc).await?;
 
-        let mut locations_by_version: HashMap<String, Vec<String>> = HashMap::new();
+        // Raw encounters by area, by version.
+        let mut encounters_by_version: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
+        let mut version_names = BTreeMap::new();
 
         for location_area_encounter in location_area_encounters {
-            let location_area_name = location_area_encounter
-                .location_area
-                .follow(rc)
-                .await?
-                .names
-                .find_by_lang(lang)
-                .unwrap_or_else(|| "Location area name not found".to_string());
+            let location_area = location_area_encounter.location_area.follow(rc).await?;
+            let location = location_area.location.follow(rc).await?;
+            let area_key = (
+                get_area_name(&location_area, &location, lang),
+                location.name,
+            );
 
             for version_detail in location_area_encounter.version_details {
-                let version_id = version_detail.version.name;
-                locations_by_version
-                    .entry(version_id)
+                let version = version_detail.version.follow(rc).await?;
+                let version_key = (version.id, version.name.clone());
+                version_names.insert(
+                    version_key.clone(),
+                    version.names.find_by_lang(lang).unwrap_or(version.name),
+                );
+
+                encounters_by_version
+                    .entry(version_key)
                     .or_default()
-                    .push(location_area_name.clone());
+                    .entry(area_key.clone())
+                    .or_default()
+                    .extend(version_detail.encounter_details);
             }
         }
 
-        let version_group_id_and_names =
-            utils::get_version_group_id_and_names(locations_by_version.keys().collect());
+        let mut versions = Vec::with_capacity(encounters_by_version.len());
+        for (version_key, encounters_by_area) in encounters_by_version {
+            let mut areas = Vec::with_capacity(encounters_by_area.len());
+            for ((name, location_id), encounters) in encounters_by_area {
+                areas.push(AreaEncounters {
+                    location_id,
+                    name,
+                    encounters: group_encounters(&encounters, rc, lang).await?,
+                });
+            }
 
-        Ok(Locations {
-            version_group_id_and_names,
-            locations_by_version,
-        })
+            versions.push(VersionLocations {
+                version_name: version_names.remove(&version_key).unwrap_or_default(),
+                version_id: version_key.1,
+                areas,
+            });
+        }
+
+        Ok(Locations { versions })
+    }
+}
+
+/// Returns the name of `location_area`, or of its `location` if it has none of its own.
+pub(crate) fn get_area_name(
+    location_area: &LocationArea,
+    location: &Location,
+    lang: &Lang,
+) -> String {
+    let location_name = location
+        .names
+        .find_by_lang(lang)
+        .unwrap_or_else(|| location.name.clone());
+
+    match location_area.names.find_by_lang(lang) {
+        Some(area_name) if area_name != location_name => area_name,
+        _ => location_name,
+    }
+}
+
+/// Groups `encounters` sharing the same method and conditions.
+pub(crate) async fn group_encounters(
+    encounters: &[Encounter],
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<Vec<EncounterDetail>> {
+    let mut encounter_details: BTreeMap<_, EncounterDetail> = BTreeMap::new();
+
+    for encounter in encounters {
+        let method =
+            utils::get_name_by_lang(&encounter.method, |method| method.names, rc, lang).await?;
+        let mut conditions = BTreeSet::new();
+        for condition_value in &encounter.condition_values {
+            conditions.insert(
+                utils::get_name_by_lang(condition_value, |value| value.names, rc, lang).await?,
+            );
+        }
+        let conditions: Vec<_> = conditions.into_iter().collect();
+
+        encounter_details
+            .entry((method.clone(), conditions.clone()))
+            .and_modify(|encounter_detail| {
+                encounter_detail.min_level = encounter_detail.min_level.min(encounter.min_level);
+                encounter_detail.max_level = encounter_detail.max_level.max(encounter.max_level);
+                encounter_detail.chance += encounter.chance;
+            })
+            .or_insert(EncounterDetail {
+                method,
+                min_level: encounter.min_level,
+                max_level: encounter.max_level,
+                chance: encounter.chance,
+                conditions,
+            });
     }
+
+    Ok(encounter_details.into_values().collect())
 }
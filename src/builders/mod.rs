@@ -2,13 +2,28 @@ use anyhow::Result;
 use async_trait::async_trait;
 use rustemon::client::RustemonClient;
 
+use crate::find_by_lang::Lang;
+
+pub(crate) mod ability_detail;
 pub(crate) mod all_pokemon;
+pub(crate) mod browse;
 pub(crate) mod card;
+pub(crate) mod coverage;
 pub(crate) mod descriptions;
+pub(crate) mod evolutions;
+pub(crate) mod held_items;
+pub(crate) mod item_detail;
+pub(crate) mod location_detail;
 pub(crate) mod locations;
+pub(crate) mod move_detail;
 pub(crate) mod moves;
+pub(crate) mod pokedex_detail;
 pub(crate) mod pokemon;
+pub(crate) mod regions;
 pub(crate) mod search;
+pub(crate) mod stats;
+pub(crate) mod type_chart;
+pub(crate) mod type_detail;
 pub(crate) mod weaknesses;
 
 #[async_trait]
@@ -16,5 +31,11 @@ pub(crate) trait Builder<T>
 where
     Self: Sized,
 {
-    async fn build(data: &T, rc: &RustemonClient, lang: &str) -> Result<Self>;
+    async fn build(data: &T, rc: &RustemonClient, lang: &Lang) -> Result<Self>;
+}
+
+/// The data of one page among the pages of a directory, such as the learners of a move.
+pub(crate) trait Element {
+    /// The identifier naming the page in its directory.
+    fn element_id(&self) -> &str;
 }
@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, Follow};
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The Pokemon learning a move, by learn method.
+pub(crate) struct MoveLearners {
+    move_id: String,
+    learners_by_method: BTreeMap<String, Vec<PokemonElement>>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct MoveDetail {
+    move_id: String,
+    name: String,
+    type_: String,
+    category: String,
+    power: Option<i64>,
+    accuracy: Option<i64>,
+    pp: Option<i64>,
+    priority: i64,
+    target: String,
+    effect: Option<String>,
+    flavor_texts: Vec<(&'static str, String)>,
+    learn_methods: Vec<LearnMethod>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct LearnMethod {
+    name: String,
+    learners: Vec<PokemonElement>,
+}
+
+impl Element for MoveLearners {
+    fn element_id(&self) -> &str {
+        &self.move_id
+    }
+}
+
+/// Gathers, for every move learned by the generated Pokemon, which of them learn it and how.
+pub(crate) fn collect_move_learners(generated_pokemons: &[GeneratedPokemon]) -> Vec<MoveLearners> {
+    let mut learners_by_move: BTreeMap<String, BTreeMap<String, Vec<PokemonElement>>> =
+        BTreeMap::new();
+
+    for generated_pokemon in generated_pokemons {
+        for pokemon_move in &generated_pokemon.pokemon().moves {
+            let methods: BTreeSet<_> = pokemon_move
+                .version_group_details
+                .iter()
+                .map(|version_group_detail| &version_group_detail.move_learn_method.name)
+                .collect();
+
+            let learners_by_method = learners_by_move
+                .entry(pokemon_move.move_.name.clone())
+                .or_default();
+            for method in methods {
+                learners_by_method
+                    .entry(method.clone())
+                    .or_default()
+                    .push(generated_pokemon.element().clone());
+            }
+        }
+    }
+
+    learners_by_move
+        .into_iter()
+        .map(|(move_id, learners_by_method)| MoveLearners {
+            move_id,
+            learners_by_method,
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Builder<MoveLearners> for MoveDetail {
+    async fn build(move_learners: &MoveLearners, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let move_id = &move_learners.move_id;
+        let move_ = rustemon::moves::move_::get_by_name(move_id, rc).await?;
+
+        let name = move_
+            .names
+            .find_by_lang(lang)
+            .with_context(|| format!("No {} name for move {:?}", lang, move_id))?;
+
+        let target = move_
+            .target
+            .follow(rc)
+            .await?
+            .names
+            .find_by_lang(lang)
+            .unwrap_or_else(|| move_.target.name.clone());
+
+        let effect =
+            move_
+                .effect_entries
+                .find_by_lang(lang)
+                .map(|effect| match move_.effect_chance {
+                    Some(effect_chance) => {
+                        effect.replace("$effect_chance", &effect_chance.to_string())
+                    }
+                    None => effect,
+                });
+
+        let flavor_texts = utils::get_flavor_texts_by_version_group(
+            &move_.flavor_text_entries,
+            |flavor_text_entry| {
+                (
+                    &flavor_text_entry.language.name,
+                    &flavor_text_entry.version_group.name,
+                    &flavor_text_entry.flavor_text,
+                )
+            },
+            lang,
+        );
+
+        let mut learn_methods = Vec::with_capacity(move_learners.learners_by_method.len());
+        for (method, learners) in &move_learners.learners_by_method {
+            let method_name = rustemon::moves::move_learn_method::get_by_name(method, rc)
+                .await?
+                .names
+                .find_by_lang(lang)
+                .unwrap_or_else(|| method.clone());
+
+            let mut learners = learners.clone();
+            learners.sort_by_key(PokemonElement::id);
+
+            learn_methods.push(LearnMethod {
+                name: method_name,
+                learners,
+            });
+        }
+
+        Ok(Self {
+            move_id: move_id.clone(),
+            name,
+            type_: move_.type_.name,
+            category: move_.damage_class.name,
+            power: move_.power,
+            accuracy: move_.accuracy,
+            pp: move_.pp,
+            priority: move_.priority,
+            target,
+            effect,
+            flavor_texts,
+            learn_methods,
+        })
+    }
+}
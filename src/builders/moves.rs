@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -6,27 +6,49 @@ use rustemon::{
     client::RustemonClient,
     model::{
         moves::Move,
-        pokemon::{PokemonMove, PokemonMoveVersion},
+        pokemon::{Pokemon, PokemonMove, PokemonMoveVersion},
         resource::NamedApiResource,
     },
     Follow,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{find_by_lang::FindWordingByLang, utils};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+use super::{pokemon::SelectedPokemon, Builder};
+
+/// Gender rate of the species having only females.
+const FEMALE_ONLY_GENDER_RATE: i64 = 8;
+
+/// Gender rate of the genderless species.
+const GENDERLESS_GENDER_RATE: i64 = -1;
 
-use super::Builder;
+/// Egg group of the species that cannot breed.
+const NO_EGGS_EGG_GROUP: &str = "no-eggs";
 
 #[derive(Serialize)]
 pub(crate) struct Moves {
     pokemon_name: String,
     version_group_id_and_names: Vec<(&'static str, &'static str)>,
-    moves_learned_by_level_by_version_group: HashMap<String, Vec<MoveByLevel>>,
-    moves_learned_by_machine_by_version_group: HashMap<String, Vec<MoveByMachine>>,
+    moves_learned_by_level_by_version_group: BTreeMap<String, Vec<MoveByLevel>>,
+    moves_learned_by_machine_by_version_group: BTreeMap<String, Vec<MoveByMachine>>,
+    moves_learned_by_other_methods: Vec<MovesByMethod>,
+}
+
+/// The moves learned by a method other than level up and machines, such as eggs or tutors.
+#[derive(Serialize)]
+pub(crate) struct MovesByMethod {
+    method_id: String,
+    method_name: String,
+    moves_by_version_group: BTreeMap<String, Vec<MoveByMethod>>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub(crate) struct MoveByLevel {
+    move_id: String,
     name: String,
     lvl_learned_at: u8,
     type_: String,
@@ -38,6 +60,7 @@ pub(crate) struct MoveByLevel {
 
 #[derive(Serialize, Deserialize)]
 pub(crate) struct MoveByMachine {
+    move_id: String,
     name: String,
     machine_name: String,
     type_: String,
@@ -47,14 +70,41 @@ pub(crate) struct MoveByMachine {
     pp: u8,
 }
 
+#[derive(Serialize, Deserialize)]
+pub(crate) struct MoveByMethod {
+    move_id: String,
+    name: String,
+    type_: String,
+    category: String,
+    power: u8,
+    accuracy: u8,
+    pp: u8,
+    /// For egg moves, the Pokemon of a shared egg group that can pass the move on.
+    parents: Vec<EggParent>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub(crate) struct EggParent {
+    pokemon_id: String,
+    name: String,
+    /// Whether the Pokemon has a page to link to.
+    is_generated: bool,
+}
+
 struct MoveBuild {
-    by_level: HashMap<String, Vec<MoveByLevel>>,
-    by_machine: HashMap<String, Vec<MoveByMachine>>,
+    by_level: BTreeMap<String, Vec<MoveByLevel>>,
+    by_machine: BTreeMap<String, Vec<MoveByMachine>>,
+    by_method: BTreeMap<String, BTreeMap<String, Vec<MoveByMethod>>>,
 }
 
 #[async_trait]
-impl Builder<String> for Moves {
-    async fn build(id: &String, rc: &RustemonClient, lang: &str) -> Result<Self> {
+impl Builder<SelectedPokemon> for Moves {
+    async fn build(
+        selected_pokemon: &SelectedPokemon,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let id = selected_pokemon.pokemon_id();
         let pokemon = rustemon::pokemon::pokemon::get_by_name(id, rc).await?;
         let pokemon_name = pokemon
             .species
@@ -64,18 +114,64 @@ impl Builder<String> for Moves {
             .find_by_lang(lang)
             .with_context(|| format!("No {} name for {}", lang, id))?;
 
-        let mut moves_learned_by_level_by_version_group = HashMap::new();
-        let mut moves_learned_by_machine_by_version_group = HashMap::new();
+        let mut moves_learned_by_level_by_version_group = BTreeMap::new();
+        let mut moves_learned_by_machine_by_version_group = BTreeMap::new();
+        let mut moves_learned_by_method: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
 
         for pokemon_move in &pokemon.moves {
             let MoveBuild {
                 by_level,
                 by_machine,
+                by_method,
             } = MoveBuild::build(pokemon_move, rc, lang).await?;
             utils::fuse_maps_in_place(&mut moves_learned_by_level_by_version_group, by_level);
             utils::fuse_maps_in_place(&mut moves_learned_by_machine_by_version_group, by_machine);
+            for (method, moves_by_version_group) in by_method {
+                utils::fuse_maps_in_place(
+                    moves_learned_by_method.entry(method).or_default(),
+                    moves_by_version_group,
+                );
+            }
+        }
+
+        if let Some(egg_moves_by_version_group) = moves_learned_by_method.get_mut("egg") {
+            let egg_parents = find_egg_parents(&pokemon, selected_pokemon, rc, lang).await?;
+            for (version_group, egg_moves) in egg_moves_by_version_group {
+                for egg_move in egg_moves {
+                    egg_move.parents = egg_parents
+                        .get(&(egg_move.move_id.clone(), version_group.clone()))
+                        .cloned()
+                        .unwrap_or_default();
+                }
+            }
         }
 
+        let mut moves_learned_by_other_methods = Vec::with_capacity(moves_learned_by_method.len());
+        for (method_id, mut moves_by_version_group) in moves_learned_by_method {
+            let method = rustemon::moves::move_learn_method::get_by_name(&method_id, rc).await?;
+            moves_by_version_group
+                .values_mut()
+                .for_each(|moves_by_method| {
+                    moves_by_method.sort_by(|move_by_method1, move_by_method2| {
+                        move_by_method1.name.cmp(&move_by_method2.name)
+                    })
+                });
+
+            moves_learned_by_other_methods.push((
+                method.id,
+                MovesByMethod {
+                    method_name: method.names.find_by_lang(lang).unwrap_or(method.name),
+                    method_id,
+                    moves_by_version_group,
+                },
+            ));
+        }
+        moves_learned_by_other_methods.sort_by_key(|(method_id, _)| *method_id);
+        let moves_learned_by_other_methods = moves_learned_by_other_methods
+            .into_iter()
+            .map(|(_, moves_by_method)| moves_by_method)
+            .collect::<Vec<_>>();
+
         moves_learned_by_level_by_version_group
             .values_mut()
             .for_each(|moves_by_level| {
@@ -91,7 +187,15 @@ impl Builder<String> for Moves {
                 })
             });
         let version_group_id_and_names = utils::get_version_group_id_and_names(
-            moves_learned_by_level_by_version_group.keys().collect(),
+            moves_learned_by_level_by_version_group
+                .keys()
+                .chain(moves_learned_by_machine_by_version_group.keys())
+                .chain(
+                    moves_learned_by_other_methods
+                        .iter()
+                        .flat_map(|moves_by_method| moves_by_method.moves_by_version_group.keys()),
+                )
+                .collect(),
         );
 
         Ok(Self {
@@ -99,13 +203,14 @@ impl Builder<String> for Moves {
             version_group_id_and_names,
             moves_learned_by_level_by_version_group,
             moves_learned_by_machine_by_version_group,
+            moves_learned_by_other_methods,
         })
     }
 }
 
 #[async_trait]
 impl Builder<PokemonMove> for MoveBuild {
-    async fn build(pokemon_move: &PokemonMove, rc: &RustemonClient, lang: &str) -> Result<Self> {
+    async fn build(pokemon_move: &PokemonMove, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
         let move_ = pokemon_move.move_.follow(rc).await?;
 
         let move_name = move_
@@ -113,8 +218,9 @@ impl Builder<PokemonMove> for MoveBuild {
             .find_by_lang(lang)
             .with_context(|| format!("No {} name for move {:?}", lang, move_.name))?;
 
-        let mut by_level = HashMap::new();
-        let mut by_machine = HashMap::new();
+        let mut by_level = BTreeMap::new();
+        let mut by_machine = BTreeMap::new();
+        let mut by_method: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
         for PokemonMoveVersion {
             move_learn_method: NamedApiResource { name: mlm_name, .. },
             version_group: NamedApiResource { name: vg_name, .. },
@@ -130,13 +236,19 @@ impl Builder<PokemonMove> for MoveBuild {
                     .entry(vg_name.clone())
                     .or_insert_with(Vec::new)
                     .push(build_move_by_machine(&move_name, &move_, vg_name, rc, lang).await?),
-                _ => (),
+                _ => by_method
+                    .entry(mlm_name.clone())
+                    .or_default()
+                    .entry(vg_name.clone())
+                    .or_default()
+                    .push(build_move_by_method(&move_name, &move_)),
             }
         }
 
         Ok(Self {
             by_level,
             by_machine,
+            by_method,
         })
     }
 }
@@ -147,6 +259,7 @@ fn build_move_by_level(
     move_: &Move,
 ) -> Result<MoveByLevel> {
     Ok(MoveByLevel {
+        move_id: move_.name.clone(),
         name: move_name.to_owned(),
         lvl_learned_at: level_learned_at as u8,
         type_: move_.type_.name.clone(),
@@ -162,7 +275,7 @@ async fn build_move_by_machine(
     move_: &Move,
     version_group: &String,
     rc: &RustemonClient,
-    lang: &str,
+    lang: &Lang,
 ) -> Result<MoveByMachine> {
     let machine_name = move_
         .machines
@@ -170,7 +283,7 @@ async fn build_move_by_machine(
         .find(|machine_by_version_detail| {
             &machine_by_version_detail.version_group.name == version_group
         })
-        .unwrap()
+        .with_context(|| format!("No machine in {} for move {:?}", version_group, move_.name))?
         .machine
         .follow(rc)
         .await?
@@ -179,9 +292,10 @@ async fn build_move_by_machine(
         .await?
         .names
         .find_by_lang(lang)
-        .unwrap();
+        .with_context(|| format!("No {} machine name for move {:?}", lang, move_.name))?;
 
     Ok(MoveByMachine {
+        move_id: move_.name.clone(),
         name: move_name.to_owned(),
         machine_name,
         type_: move_.type_.name.clone(),
@@ -191,3 +305,96 @@ async fn build_move_by_machine(
         pp: move_.pp.unwrap_or_default() as u8,
     })
 }
+
+fn build_move_by_method(move_name: &str, move_: &Move) -> MoveByMethod {
+    MoveByMethod {
+        move_id: move_.name.clone(),
+        name: move_name.to_owned(),
+        type_: move_.type_.name.clone(),
+        category: move_.damage_class.name.clone(),
+        power: move_.power.unwrap_or_default() as u8,
+        accuracy: move_.accuracy.unwrap_or_default() as u8,
+        pp: move_.pp.unwrap_or_default() as u8,
+        parents: Vec::new(),
+    }
+}
+
+/// Returns, by move and version group, the Pokemon sharing an egg group with `pokemon`
+/// that can pass the move on, learning it by level up or as an egg move themselves.
+async fn find_egg_parents(
+    pokemon: &Pokemon,
+    selected_pokemon: &SelectedPokemon,
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<HashMap<(String, String), Vec<EggParent>>> {
+    let pokemon_specie = pokemon.species.follow(rc).await?;
+    if pokemon_specie
+        .egg_groups
+        .iter()
+        .any(|egg_group| egg_group.name == NO_EGGS_EGG_GROUP)
+    {
+        return Ok(HashMap::new());
+    }
+
+    let mut parent_species = BTreeSet::new();
+    for egg_group in &pokemon_specie.egg_groups {
+        parent_species.extend(
+            egg_group
+                .follow(rc)
+                .await?
+                .pokemon_species
+                .into_iter()
+                .map(|parent_specie| parent_specie.name),
+        );
+    }
+    parent_species.remove(&pokemon_specie.name);
+
+    let mut egg_parents: HashMap<_, Vec<_>> = HashMap::new();
+    for parent_specie in parent_species {
+        let parent_specie =
+            rustemon::pokemon::pokemon_species::get_by_name(&parent_specie, rc).await?;
+        // Only males pass their moves on, which female-only and genderless species lack.
+        if parent_specie.gender_rate == FEMALE_ONLY_GENDER_RATE
+            || parent_specie.gender_rate == GENDERLESS_GENDER_RATE
+        {
+            continue;
+        }
+
+        let parent = match parent_specie
+            .varieties
+            .iter()
+            .find(|variety| variety.is_default)
+        {
+            Some(variety) => variety.pokemon.follow(rc).await?,
+            None => continue,
+        };
+        let egg_parent = EggParent {
+            name: utils::get_pokemon_name_by_lang(&parent, &parent_specie, lang, rc).await?,
+            is_generated: selected_pokemon.is_selected(&parent.name),
+            pokemon_id: parent.name,
+        };
+
+        for parent_move in parent.moves {
+            let version_groups: BTreeSet<_> = parent_move
+                .version_group_details
+                .into_iter()
+                .filter(|version_group_detail| {
+                    matches!(
+                        version_group_detail.move_learn_method.name.as_str(),
+                        "level-up" | "egg"
+                    )
+                })
+                .map(|version_group_detail| version_group_detail.version_group.name)
+                .collect();
+
+            for version_group in version_groups {
+                egg_parents
+                    .entry((parent_move.move_.name.clone(), version_group))
+                    .or_default()
+                    .push(egg_parent.clone());
+            }
+        }
+    }
+
+    Ok(egg_parents)
+}
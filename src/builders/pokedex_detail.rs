@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::client::RustemonClient;
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The national Pokedex, already listed by the all Pokemons page.
+pub(crate) const NATIONAL_POKEDEX: &str = "national";
+
+/// The generated Pokemon of a regional Pokedex, with their number in it.
+pub(crate) struct PokedexMembers {
+    pokedex_id: String,
+    entries: Vec<(i64, PokemonElement)>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct PokedexDetail {
+    pokedex_id: String,
+    name: String,
+    region_name: Option<String>,
+    description: Option<String>,
+    entries: Vec<PokedexEntry>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct PokedexEntry {
+    entry_number: i64,
+    pokemon: PokemonElement,
+}
+
+/// The regional Pokedexes having generated Pokemon, for the Pokedexes index.
+#[derive(Serialize)]
+pub(crate) struct Pokedexes {
+    pokedexes: Vec<PokedexLink>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct PokedexLink {
+    pokedex_id: String,
+    name: String,
+    region_name: Option<String>,
+}
+
+impl Element for PokedexMembers {
+    fn element_id(&self) -> &str {
+        &self.pokedex_id
+    }
+}
+
+/// Gathers, for every regional Pokedex, the generated Pokemon listed in it.
+pub(crate) fn collect_pokedex_members(
+    generated_pokemons: &[GeneratedPokemon],
+) -> Vec<PokedexMembers> {
+    let mut entries_by_pokedex: BTreeMap<String, Vec<_>> = BTreeMap::new();
+
+    for generated_pokemon in generated_pokemons {
+        for pokedex_number in &generated_pokemon.species().pokedex_numbers {
+            if pokedex_number.pokedex.name == NATIONAL_POKEDEX {
+                continue;
+            }
+
+            entries_by_pokedex
+                .entry(pokedex_number.pokedex.name.clone())
+                .or_default()
+                .push((
+                    pokedex_number.entry_number,
+                    generated_pokemon.element().clone(),
+                ));
+        }
+    }
+
+    entries_by_pokedex
+        .into_iter()
+        .map(|(pokedex_id, entries)| PokedexMembers {
+            pokedex_id,
+            entries,
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Builder<PokedexMembers> for PokedexDetail {
+    async fn build(
+        pokedex_members: &PokedexMembers,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let pokedex_id = &pokedex_members.pokedex_id;
+        let pokedex = rustemon::games::pokedex::get_by_name(pokedex_id, rc).await?;
+
+        let mut entries: Vec<_> = pokedex_members
+            .entries
+            .iter()
+            .map(|(entry_number, pokemon)| PokedexEntry {
+                entry_number: *entry_number,
+                pokemon: pokemon.clone(),
+            })
+            .collect();
+        entries.sort_by_key(|entry| (entry.entry_number, entry.pokemon.id()));
+
+        Ok(Self {
+            pokedex_id: pokedex_id.clone(),
+            name: pokedex.names.find_by_lang(lang).unwrap_or(pokedex.name),
+            region_name: match &pokedex.region {
+                Some(region) => {
+                    Some(utils::get_name_by_lang(region, |region| region.names, rc, lang).await?)
+                }
+                None => None,
+            },
+            description: pokedex.descriptions.find_by_lang(lang),
+            entries,
+        })
+    }
+}
+
+#[async_trait]
+impl Builder<Vec<String>> for Pokedexes {
+    async fn build(pokedex_ids: &Vec<String>, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let mut pokedexes = Vec::with_capacity(pokedex_ids.len());
+
+        for pokedex_id in pokedex_ids {
+            let pokedex = rustemon::games::pokedex::get_by_name(pokedex_id, rc).await?;
+            let region_name = match &pokedex.region {
+                Some(region) => {
+                    Some(utils::get_name_by_lang(region, |region| region.names, rc, lang).await?)
+                }
+                None => None,
+            };
+
+            pokedexes.push((
+                pokedex.id,
+                PokedexLink {
+                    pokedex_id: pokedex_id.clone(),
+                    name: pokedex.names.find_by_lang(lang).unwrap_or(pokedex.name),
+                    region_name,
+                },
+            ));
+        }
+        pokedexes.sort_by_key(|(id, _)| *id);
+
+        Ok(Self {
+            pokedexes: pokedexes
+                .into_iter()
+                .map(|(_, pokedex_link)| pokedex_link)
+                .collect(),
+        })
+    }
+}
@@ -1,33 +1,119 @@
+use std::{collections::BTreeSet, sync::Arc};
+
 use anyhow::Result;
 use async_trait::async_trait;
 use rustemon::client::RustemonClient;
 use serde::Serialize;
 
+use crate::find_by_lang::Lang;
+
 use super::{
-    card::Card, descriptions::Descriptions, locations::Locations, moves::Moves,
+    card::Card, coverage::Coverage, descriptions::Descriptions, evolutions::Evolutions,
+    held_items::HeldItems, locations::Locations, moves::Moves, stats::Stats,
     weaknesses::Weaknesses, Builder,
 };
 
+/// A Pokemon to build the page of, along with all the Pokemon selected for generation,
+/// so that the page only links to Pokemon pages that exist.
+pub(crate) struct SelectedPokemon {
+    pokemon_id: String,
+    selected_pokemon_ids: Arc<BTreeSet<String>>,
+}
+
+impl SelectedPokemon {
+    pub fn new(pokemon_id: String, selected_pokemon_ids: Arc<BTreeSet<String>>) -> Self {
+        Self {
+            pokemon_id,
+            selected_pokemon_ids,
+        }
+    }
+
+    pub fn pokemon_id(&self) -> &String {
+        &self.pokemon_id
+    }
+
+    /// Tells if `pokemon_id` has a page.
+    pub fn is_selected(&self, pokemon_id: &str) -> bool {
+        self.selected_pokemon_ids.contains(pokemon_id)
+    }
+}
+
+/// A Pokemon page. Only the card is required,
+/// any other section that fails to build is left out and its error kept in `section_errors`.
 #[derive(Serialize)]
 pub(crate) struct Pokemon {
     card: Card,
-    descriptions: Descriptions,
-    moves: Moves,
-    weaknesses: Weaknesses,
-    locations: Locations,
+    stats: Option<Stats>,
+    descriptions: Option<Descriptions>,
+    evolutions: Option<Evolutions>,
+    moves: Option<Moves>,
+    weaknesses: Option<Weaknesses>,
+    coverage: Option<Coverage>,
+    locations: Option<Locations>,
+    held_items: Option<HeldItems>,
+    #[serde(skip)]
+    section_errors: Vec<(&'static str, anyhow::Error)>,
+}
+
+impl Pokemon {
+    /// Returns the errors of the sections that failed to build, by section name.
+    pub fn take_section_errors(&mut self) -> Vec<(&'static str, anyhow::Error)> {
+        std::mem::take(&mut self.section_errors)
+    }
 }
 
 #[async_trait]
-impl Builder<String> for Pokemon {
-    async fn build(id: &String, rc: &RustemonClient, lang: &str) -> Result<Self> {
+impl Builder<SelectedPokemon> for Pokemon {
+    async fn build(
+        selected_pokemon: &SelectedPokemon,
+        rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let id = selected_pokemon.pokemon_id();
+        let (
+            card,
+            stats,
+            descriptions,
+            evolutions,
+            moves,
+            weaknesses,
+            coverage,
+            locations,
+            held_items,
+        ) = tokio::join!(
+            Card::build(selected_pokemon, rc, lang),
+            Stats::build(id, rc, lang),
+            Descriptions::build(id, rc, lang),
+            Evolutions::build(selected_pokemon, rc, lang),
+            Moves::build(selected_pokemon, rc, lang),
+            Weaknesses::build(id, rc, lang),
+            Coverage::build(id, rc, lang),
+            Locations::build(id, rc, lang),
+            HeldItems::build(id, rc, lang),
+        );
+
+        let mut section_errors = Vec::new();
         Ok(Pokemon {
-            card: Card::build(id, rc, lang).await?,
-            descriptions: Descriptions::build(id, rc, lang).await?,
-            moves: Moves::build(id, rc, lang).await?,
-            weaknesses: Weaknesses::build(id, rc, lang).await?,
-            locations: Locations::default(),
-            // TODO: Uncomment this when data has been merged
-            // locations: Locations::build(id, rc, lang).await?,
+            card: card?,
+            stats: ok_or_push("stats", stats, &mut section_errors),
+            descriptions: ok_or_push("descriptions", descriptions, &mut section_errors),
+            evolutions: ok_or_push("evolutions", evolutions, &mut section_errors),
+            moves: ok_or_push("moves", moves, &mut section_errors),
+            weaknesses: ok_or_push("weaknesses", weaknesses, &mut section_errors),
+            coverage: ok_or_push("coverage", coverage, &mut section_errors),
+            locations: ok_or_push("locations", locations, &mut section_errors),
+            held_items: ok_or_push("held items", held_items, &mut section_errors),
+            section_errors,
         })
     }
 }
+
+fn ok_or_push<T>(
+    section: &'static str,
+    result: Result<T>,
+    section_errors: &mut Vec<(&'static str, anyhow::Error)>,
+) -> Option<T> {
+    result
+        .map_err(|error| section_errors.push((section, error)))
+        .ok()
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, Follow};
+use serde::Serialize;
+
+use super::Builder;
+use crate::find_by_lang::{FindWordingByLang, Lang};
+
+/// The generated locations, by region.
+#[derive(Serialize)]
+pub(crate) struct Regions {
+    regions: Vec<RegionLocations>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct RegionLocations {
+    name: Option<String>,
+    locations: Vec<LocationLink>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct LocationLink {
+    location_id: String,
+    name: String,
+}
+
+#[async_trait]
+impl Builder<Vec<String>> for Regions {
+    async fn build(location_ids: &Vec<String>, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        // Locations without region come last.
+        let mut locations_by_region: BTreeMap<_, Vec<_>> = BTreeMap::new();
+
+        for location_id in location_ids {
+            let location = rustemon::locations::location::get_by_name(location_id, rc).await?;
+            let region_key = match &location.region {
+                Some(region) => {
+                    let region = region.follow(rc).await?;
+                    let region_name = region.names.find_by_lang(lang).unwrap_or(region.name);
+                    (region.id, Some(region_name))
+                }
+                None => (i64::MAX, None),
+            };
+
+            locations_by_region
+                .entry(region_key)
+                .or_default()
+                .push(LocationLink {
+                    location_id: location_id.clone(),
+                    name: location
+                        .names
+                        .find_by_lang(lang)
+                        .unwrap_or_else(|| location.name.clone()),
+                });
+        }
+
+        Ok(Self {
+            regions: locations_by_region
+                .into_iter()
+                .map(|((_, name), mut locations)| {
+                    locations.sort_by(|location1, location2| location1.name.cmp(&location2.name));
+                    RegionLocations { name, locations }
+                })
+                .collect(),
+        })
+    }
+}
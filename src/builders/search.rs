@@ -2,12 +2,12 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use rustemon::{client::RustemonClient, Follow};
+use rustemon::client::RustemonClient;
 use serde::{Deserialize, Serialize};
 use unidecode::unidecode;
 
-use super::Builder;
-use crate::find_by_lang::FindWordingByLang;
+use super::{all_pokemon::GeneratedPokemon, Builder};
+use crate::find_by_lang::{FindWordingByLang, Lang};
 
 #[derive(Serialize, Deserialize)]
 pub(crate) struct SearchElement {
@@ -25,49 +25,35 @@ pub(crate) struct Search {
 }
 
 #[async_trait]
-impl Builder<Vec<(String, PathBuf)>> for Search {
-    async fn build(data: &Vec<(String, PathBuf)>, rc: &RustemonClient, lang: &str) -> Result<Self> {
-        let mut search_elements = Vec::with_capacity(data.len());
-
-        for (pokemon_id, path) in data {
-            let pokemon = rustemon::pokemon::pokemon::get_by_name(pokemon_id, rc).await?;
-
-            let pokemon_species = pokemon.species.follow(rc).await?;
-
-            let pokemon_index = pokemon_species
-                .pokedex_numbers
-                .iter()
-                .find(|pokemon_number| pokemon_number.pokedex.name == "national")
-                .map(|pokemon_number| pokemon_number.entry_number)
-                .unwrap_or(9999);
-
-            let names = pokemon_species.names;
-            let display_name = names
-                .find_by_lang(lang)
-                .with_context(|| format!("No {} name for {}", lang, pokemon_id))?;
+impl Builder<Vec<GeneratedPokemon>> for Search {
+    async fn build(
+        generated_pokemons: &Vec<GeneratedPokemon>,
+        _rc: &RustemonClient,
+        lang: &Lang,
+    ) -> Result<Self> {
+        let mut search_elements = Vec::with_capacity(generated_pokemons.len());
+
+        for generated_pokemon in generated_pokemons {
+            let pokemon_id = &generated_pokemon.pokemon().name;
+            let pokemon_element = generated_pokemon.element();
+            let names = &generated_pokemon.species().names;
+            let search_name_en = names
+                .find_by_lang(&Lang::new("en".to_owned(), lang.ids().to_vec()))
+                .with_context(|| format!("No {} name for {}", "en", pokemon_id))?
+                .to_lowercase();
             let search_name_fr = names
-                .find_by_lang("fr")
+                .find_by_lang(&Lang::new("fr".to_owned(), lang.ids().to_vec()))
                 .with_context(|| format!("No {} name for {}", "fr", pokemon_id))?
                 .to_lowercase();
             let search_name_fr = unidecode(&search_name_fr);
 
-            let pokemon_sprite = pokemon
-                .sprites
-                .front_default
-                .unwrap_or_else(|| {
-                    format!(
-                        "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/{}.png",
-                        pokemon.id
-                    )
-                });
-
             search_elements.push(SearchElement {
-                id: pokemon_index,
-                search_name_en: display_name.to_lowercase(),
+                id: pokemon_element.id(),
+                search_name_en,
                 search_name_fr,
-                display_name,
-                sprite: pokemon_sprite,
-                path: path.clone(),
+                display_name: pokemon_element.display_name().to_owned(),
+                sprite: pokemon_element.sprite().to_owned(),
+                path: pokemon_element.path().to_owned(),
             });
         }
 
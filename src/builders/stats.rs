@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rustemon::{client::RustemonClient, Follow};
+use serde::Serialize;
+
+use super::Builder;
+use crate::find_by_lang::{FindWordingByLang, Lang};
+
+/// Highest base stat a Pokemon can have, used to scale the stat bars.
+const MAX_BASE_STAT: i64 = 255;
+
+const MAX_IV: i64 = 31;
+
+const MAX_EV: i64 = 252;
+
+#[derive(Serialize)]
+pub(crate) struct Stats {
+    stats: Vec<Stat>,
+    total: i64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct Stat {
+    id: String,
+    name: String,
+    base: i64,
+    percentage: f32,
+    lvl_50: StatRange,
+    lvl_100: StatRange,
+}
+
+/// Lowest and highest values of a stat at a given level,
+/// from no IV, no EV and a hindering nature to maximum IVs, EVs and a beneficial nature.
+#[derive(Serialize)]
+pub(crate) struct StatRange {
+    min: i64,
+    max: i64,
+}
+
+#[async_trait]
+impl Builder<String> for Stats {
+    async fn build(id: &String, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let pokemon_stats = rustemon::pokemon::pokemon::get_by_name(id, rc).await?.stats;
+
+        let mut stats = Vec::with_capacity(pokemon_stats.len());
+        let mut total = 0;
+
+        for pokemon_stat in pokemon_stats {
+            let stat_id = pokemon_stat.stat.name.clone();
+            let stat_name = pokemon_stat
+                .stat
+                .follow(rc)
+                .await?
+                .names
+                .find_by_lang(lang)
+                .with_context(|| format!("No {} name for stat {}", lang, stat_id))?;
+            let base = pokemon_stat.base_stat;
+            total += base;
+
+            stats.push(Stat {
+                lvl_50: StatRange::at_level(&stat_id, base, 50),
+                lvl_100: StatRange::at_level(&stat_id, base, 100),
+                id: stat_id,
+                name: stat_name,
+                base,
+                percentage: base.min(MAX_BASE_STAT) as f32 * 100.0 / MAX_BASE_STAT as f32,
+            });
+        }
+
+        Ok(Self { stats, total })
+    }
+}
+
+impl StatRange {
+    fn at_level(stat_id: &str, base: i64, level: i64) -> Self {
+        // Shedinja always has a single HP.
+        if stat_id == "hp" && base == 1 {
+            return Self { min: 1, max: 1 };
+        }
+
+        let min = raw_stat(base, 0, 0, level);
+        let max = raw_stat(base, MAX_IV, MAX_EV, level);
+
+        if stat_id == "hp" {
+            Self {
+                min: min + level + 10,
+                max: max + level + 10,
+            }
+        } else {
+            Self {
+                min: (min + 5) * 9 / 10,
+                max: (max + 5) * 11 / 10,
+            }
+        }
+    }
+}
+
+fn raw_stat(base: i64, iv: i64, ev: i64, level: i64) -> i64 {
+    (2 * base + iv + ev / 4) * level / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(stat_range: StatRange) -> (i64, i64) {
+        (stat_range.min, stat_range.max)
+    }
+
+    #[test]
+    fn hp_range_at_level_50_and_100() {
+        // Garchomp's base HP.
+        assert_eq!(bounds(StatRange::at_level("hp", 108, 50)), (168, 215));
+        assert_eq!(bounds(StatRange::at_level("hp", 108, 100)), (326, 420));
+    }
+
+    #[test]
+    fn other_stat_range_at_level_50_and_100() {
+        // Garchomp's base attack.
+        assert_eq!(bounds(StatRange::at_level("attack", 130, 50)), (121, 200));
+        assert_eq!(bounds(StatRange::at_level("attack", 130, 100)), (238, 394));
+    }
+
+    #[test]
+    fn shedinja_always_has_a_single_hp() {
+        assert_eq!(bounds(StatRange::at_level("hp", 1, 50)), (1, 1));
+        assert_eq!(bounds(StatRange::at_level("hp", 1, 100)), (1, 1));
+        // Only its HP is fixed.
+        assert_ne!(bounds(StatRange::at_level("attack", 1, 100)), (1, 1));
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rustemon::client::RustemonClient;
+use serde::Serialize;
+
+use super::{
+    weaknesses::{self, DamageMultiplicator},
+    Builder,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The damage multiplicators of every attacking type against every defending type.
+#[derive(Serialize)]
+pub(crate) struct TypeChart {
+    types: Vec<ChartType>,
+    rows: Vec<TypeChartRow>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ChartType {
+    type_id: String,
+    name: String,
+}
+
+#[derive(Serialize)]
+pub(crate) struct TypeChartRow {
+    attacking_type: ChartType,
+    damage_multiplicators: Vec<DamageMultiplicator>,
+}
+
+#[async_trait]
+impl Builder<()> for TypeChart {
+    async fn build(_: &(), rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let mut rows = Vec::new();
+
+        for type_id in utils::get_type_ids() {
+            let type_ = rustemon::pokemon::type_::get_by_name(&type_id, rc).await?;
+            let damage_relations = &type_.damage_relations;
+
+            rows.push(TypeChartRow {
+                damage_multiplicators: weaknesses::damage_multiplicators(
+                    &damage_relations.double_damage_to,
+                    &damage_relations.half_damage_to,
+                    &damage_relations.no_damage_to,
+                )
+                .into_iter()
+                .map(|(_, damage_multiplicator)| damage_multiplicator)
+                .collect(),
+                attacking_type: ChartType {
+                    name: type_
+                        .names
+                        .find_by_lang(lang)
+                        .with_context(|| format!("No {} name for type {:?}", lang, type_id))?,
+                    type_id,
+                },
+            });
+        }
+
+        let types = rows
+            .iter()
+            .map(|row| ChartType {
+                type_id: row.attacking_type.type_id.clone(),
+                name: row.attacking_type.name.clone(),
+            })
+            .collect();
+
+        Ok(Self { types, rows })
+    }
+}
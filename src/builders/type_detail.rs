@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rustemon::client::RustemonClient;
+use serde::Serialize;
+
+use super::{
+    all_pokemon::{GeneratedPokemon, PokemonElement},
+    weaknesses::{self, DamageMultiplicator},
+    Builder, Element,
+};
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
+
+/// The generated Pokemon and the moves they learn, of a type.
+pub(crate) struct TypeMembers {
+    type_id: String,
+    pokemon: Vec<PokemonElement>,
+    move_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct TypeDetail {
+    type_id: String,
+    name: String,
+    attack: Vec<(String, DamageMultiplicator)>,
+    defense: Vec<(String, DamageMultiplicator)>,
+    pokemon: Vec<PokemonElement>,
+    moves: Vec<TypeMove>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct TypeMove {
+    move_id: String,
+    name: String,
+    category: String,
+    power: Option<i64>,
+}
+
+impl Element for TypeMembers {
+    fn element_id(&self) -> &str {
+        &self.type_id
+    }
+}
+
+/// Gathers, for every type, the generated Pokemon having it and the moves of this type they learn.
+pub(crate) async fn collect_type_members(
+    generated_pokemons: &[GeneratedPokemon],
+    rc: &RustemonClient,
+) -> Result<Vec<TypeMembers>> {
+    let mut pokemon_by_type: BTreeMap<String, Vec<PokemonElement>> = BTreeMap::new();
+    let mut learned_move_ids = BTreeSet::new();
+
+    for generated_pokemon in generated_pokemons {
+        let pokemon = generated_pokemon.pokemon();
+        for pokemon_type in &pokemon.types {
+            pokemon_by_type
+                .entry(pokemon_type.type_.name.clone())
+                .or_default()
+                .push(generated_pokemon.element().clone());
+        }
+        learned_move_ids.extend(
+            pokemon
+                .moves
+                .iter()
+                .map(|pokemon_move| &pokemon_move.move_.name),
+        );
+    }
+
+    let mut type_members = Vec::new();
+    for type_id in utils::get_type_ids() {
+        let move_ids = rustemon::pokemon::type_::get_by_name(&type_id, rc)
+            .await?
+            .moves
+            .into_iter()
+            .map(|move_| move_.name)
+            .filter(|move_id| learned_move_ids.contains(move_id))
+            .collect();
+
+        type_members.push(TypeMembers {
+            pokemon: pokemon_by_type.remove(&type_id).unwrap_or_default(),
+            type_id,
+            move_ids,
+        });
+    }
+
+    Ok(type_members)
+}
+
+#[async_trait]
+impl Builder<TypeMembers> for TypeDetail {
+    async fn build(type_members: &TypeMembers, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let type_id = &type_members.type_id;
+        let type_ = rustemon::pokemon::type_::get_by_name(type_id, rc).await?;
+
+        let name = type_
+            .names
+            .find_by_lang(lang)
+            .with_context(|| format!("No {} name for type {:?}", lang, type_id))?;
+
+        let damage_relations = &type_.damage_relations;
+        let attack = weaknesses::damage_multiplicators(
+            &damage_relations.double_damage_to,
+            &damage_relations.half_damage_to,
+            &damage_relations.no_damage_to,
+        );
+        let defense = weaknesses::damage_multiplicators(
+            &damage_relations.double_damage_from,
+            &damage_relations.half_damage_from,
+            &damage_relations.no_damage_from,
+        );
+
+        let mut pokemon = type_members.pokemon.clone();
+        pokemon.sort_by_key(PokemonElement::id);
+
+        let mut moves = Vec::with_capacity(type_members.move_ids.len());
+        for move_id in &type_members.move_ids {
+            let move_ = rustemon::moves::move_::get_by_name(move_id, rc).await?;
+            moves.push(TypeMove {
+                move_id: move_id.clone(),
+                name: move_
+                    .names
+                    .find_by_lang(lang)
+                    .with_context(|| format!("No {} name for move {:?}", lang, move_id))?,
+                category: move_.damage_class.name,
+                power: move_.power,
+            });
+        }
+        moves.sort_by(|move1, move2| move1.name.cmp(&move2.name));
+
+        Ok(Self {
+            type_id: type_id.clone(),
+            name,
+            attack,
+            defense,
+            pokemon,
+            moves,
+        })
+    }
+}
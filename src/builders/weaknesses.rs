@@ -1,19 +1,28 @@
 use std::{collections::HashMap, ops::AddAssign};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
-use rustemon::{client::RustemonClient, Follow};
+use rustemon::{
+    client::RustemonClient,
+    model::{pokemon::Type, resource::NamedApiResource},
+    Follow,
+};
 use serde::{Deserialize, Serialize};
 
-use crate::utils;
+use crate::{
+    find_by_lang::{FindWordingByLang, Lang},
+    utils,
+};
 
 use super::Builder;
 
-#[derive(Deserialize, Serialize, Copy, Clone)]
+/// Ordered from the least to the most damage.
+#[derive(Deserialize, Serialize, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum DamageMultiplicator {
     Immune,
     Quarter,
     Half,
+    #[default]
     Simple,
     Double,
     Quadruple,
@@ -44,41 +53,432 @@ impl AddAssign for DamageMultiplicator {
     }
 }
 
-impl Default for DamageMultiplicator {
-    fn default() -> Self {
-        Self::Simple
+impl DamageMultiplicator {
+    pub fn factor(self) -> f32 {
+        match self {
+            DamageMultiplicator::Immune => 0.0,
+            DamageMultiplicator::Quarter => 0.25,
+            DamageMultiplicator::Half => 0.5,
+            DamageMultiplicator::Simple => 1.0,
+            DamageMultiplicator::Double => 2.0,
+            DamageMultiplicator::Quadruple => 4.0,
+        }
+    }
+
+    /// Returns the multiplicator closest to `factor`, such as `Simple` for the x1.25 of Dry Skin.
+    fn closest(factor: f32) -> Self {
+        if factor == 0.0 {
+            return DamageMultiplicator::Immune;
+        }
+
+        [
+            DamageMultiplicator::Quarter,
+            DamageMultiplicator::Half,
+            DamageMultiplicator::Simple,
+            DamageMultiplicator::Double,
+            DamageMultiplicator::Quadruple,
+        ]
+        .into_iter()
+        .min_by(|multiplicator1, multiplicator2| {
+            let distance = |multiplicator: &Self| (factor / multiplicator.factor()).log2().abs();
+            distance(multiplicator1).total_cmp(&distance(multiplicator2))
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// How an ability changes the damage taken by its holder.
+enum AbilityEffect {
+    /// No damage from these types.
+    Immune(&'static [&'static str]),
+    /// Damage from these types multiplied by this factor.
+    Factor(&'static [&'static str], f32),
+    /// Super effective damage multiplied by this factor.
+    SuperEffectiveFactor(f32),
+    /// No damage except super effective one.
+    OnlySuperEffective,
+}
+
+/// Returns the effects on the damage taken of an ability, empty if it has none.
+/// Effects depending on the move used, like Fluffy halving contact damage, are left out.
+fn ability_effects(ability_id: &str) -> &'static [AbilityEffect] {
+    match ability_id {
+        "levitate" | "earth-eater" => &[AbilityEffect::Immune(&["ground"])],
+        "flash-fire" | "well-baked-body" => &[AbilityEffect::Immune(&["fire"])],
+        "volt-absorb" | "lightning-rod" | "motor-drive" => &[AbilityEffect::Immune(&["electric"])],
+        "water-absorb" | "storm-drain" => &[AbilityEffect::Immune(&["water"])],
+        "sap-sipper" => &[AbilityEffect::Immune(&["grass"])],
+        "dry-skin" => &[
+            AbilityEffect::Immune(&["water"]),
+            AbilityEffect::Factor(&["fire"], 1.25),
+        ],
+        "thick-fat" => &[AbilityEffect::Factor(&["fire", "ice"], 0.5)],
+        "heatproof" | "water-bubble" => &[AbilityEffect::Factor(&["fire"], 0.5)],
+        "purifying-salt" => &[AbilityEffect::Factor(&["ghost"], 0.5)],
+        "fluffy" => &[AbilityEffect::Factor(&["fire"], 2.0)],
+        "filter" | "solid-rock" | "prism-armor" => &[AbilityEffect::SuperEffectiveFactor(0.75)],
+        "wonder-guard" => &[AbilityEffect::OnlySuperEffective],
+        _ => &[],
     }
 }
 
+impl AbilityEffect {
+    fn apply(&self, type_id: &str, factor: f32) -> f32 {
+        match self {
+            AbilityEffect::Immune(types) if types.contains(&type_id) => 0.0,
+            AbilityEffect::Factor(types, ability_factor) if types.contains(&type_id) => {
+                factor * ability_factor
+            }
+            AbilityEffect::SuperEffectiveFactor(ability_factor) if factor > 1.0 => {
+                factor * ability_factor
+            }
+            AbilityEffect::OnlySuperEffective if factor <= 1.0 => 0.0,
+            _ => factor,
+        }
+    }
+}
+
+/// Generation in which hidden abilities were introduced.
+const HIDDEN_ABILITIES_GENERATION: i64 = 5;
+
+/// The damage taken from each type, by group of consecutive generations sharing the same matchups.
 #[derive(Serialize)]
-pub(crate) struct Weaknesses(HashMap<String, DamageMultiplicator>);
+pub(crate) struct Weaknesses {
+    generations: Vec<GenerationsWeaknesses>,
+}
+
+/// The weaknesses from `first_generation_id` up to the last generation of `generations_name`,
+/// either from the Pokemon types alone or with the effect of one of its abilities.
+#[derive(Serialize)]
+pub(crate) struct GenerationsWeaknesses {
+    first_generation_id: String,
+    generations_name: String,
+    variants: Vec<WeaknessesVariant>,
+}
+
+#[derive(Serialize, PartialEq)]
+pub(crate) struct WeaknessesVariant {
+    ability_id: Option<String>,
+    ability_name: Option<String>,
+    weaknesses: Vec<Weakness>,
+}
+
+#[derive(Serialize, PartialEq)]
+pub(crate) struct Weakness {
+    type_id: String,
+    /// The closest multiplicator to `factor`, for display.
+    damage_multiplicator: DamageMultiplicator,
+    factor: f32,
+}
+
+/// A generation, with its number to compare it to the others.
+struct Generation {
+    id: i64,
+    name: String,
+    localized_name: String,
+}
 
 #[async_trait]
 impl Builder<String> for Weaknesses {
-    async fn build(id: &String, rc: &RustemonClient, _lang: &str) -> Result<Self> {
-        let types = rustemon::pokemon::pokemon::get_by_name(id, rc).await?.types;
+    async fn build(id: &String, rc: &RustemonClient, lang: &Lang) -> Result<Self> {
+        let pokemon = rustemon::pokemon::pokemon::get_by_name(id, rc).await?;
+        let pokemon_species = pokemon.species.follow(rc).await?;
 
-        let mut weaknesses: HashMap<_, _> = utils::get_type_ids()
-            .into_iter()
-            .map(|type_id| (type_id, DamageMultiplicator::default()))
-            .collect();
+        let generations = get_generations(rc, lang).await?;
+        let generation_id = |generation_name: &str| {
+            generations
+                .iter()
+                .find(|generation| generation.name == generation_name)
+                .map(|generation| generation.id)
+                .with_context(|| format!("Unknown generation {}", generation_name))
+        };
 
-        for type_ in types {
-            let damage_relations = type_.type_.follow(rc).await?.damage_relations;
+        let mut types = HashMap::new();
+        for type_id in utils::get_type_ids() {
+            let type_ = rustemon::pokemon::type_::get_by_name(&type_id, rc).await?;
+            types.insert(type_id, type_);
+        }
+        for pokemon_type in &pokemon.types {
+            if !types.contains_key(&pokemon_type.type_.name) {
+                types.insert(
+                    pokemon_type.type_.name.clone(),
+                    pokemon_type.type_.follow(rc).await?,
+                );
+            }
+        }
 
-            for dr in damage_relations.half_damage_from {
-                *weaknesses.entry(dr.name).or_default() += DamageMultiplicator::Half;
+        let mut abilities = Vec::new();
+        for pokemon_ability in &pokemon.abilities {
+            let effects = ability_effects(&pokemon_ability.ability.name);
+            if effects.is_empty() {
+                continue;
             }
 
-            for dr in damage_relations.double_damage_from {
-                *weaknesses.entry(dr.name).or_default() += DamageMultiplicator::Double;
+            let ability = pokemon_ability.ability.follow(rc).await?;
+            let ability_name = ability
+                .names
+                .find_by_lang(lang)
+                .unwrap_or_else(|| ability.name.clone());
+            let first_generation_id = ability_first_generation_id(
+                generation_id(&ability.generation.name)?,
+                pokemon_ability.is_hidden,
+            );
+            abilities.push((ability.name, ability_name, first_generation_id, effects));
+        }
+
+        let past_types = pokemon
+            .past_types
+            .iter()
+            .map(|past_types| {
+                Ok((
+                    generation_id(&past_types.generation.name)?,
+                    &past_types.types,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut past_damage_relations = HashMap::new();
+        for (type_id, type_) in &types {
+            past_damage_relations.insert(
+                type_id,
+                type_
+                    .past_damage_relations
+                    .iter()
+                    .map(|past_damage_relations| {
+                        Ok((
+                            generation_id(&past_damage_relations.generation.name)?,
+                            &past_damage_relations.damage_relations,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            );
+        }
+
+        let mut generations_weaknesses: Vec<GenerationsWeaknesses> = Vec::new();
+        let mut last_generation_name = String::new();
+        let species_generation_id = generation_id(&pokemon_species.generation.name)?;
+        for generation in species_generations(&generations, species_generation_id) {
+            let pokemon_types = value_at_generation(&pokemon.types, &past_types, generation.id);
+
+            let mut weaknesses = HashMap::new();
+            for pokemon_type in pokemon_types {
+                let type_ = &types[&pokemon_type.type_.name];
+                let damage_relations = value_at_generation(
+                    &type_.damage_relations,
+                    &past_damage_relations[&pokemon_type.type_.name],
+                    generation.id,
+                );
+
+                for dr in &damage_relations.half_damage_from {
+                    *weaknesses.entry(&dr.name).or_default() += DamageMultiplicator::Half;
+                }
+
+                for dr in &damage_relations.double_damage_from {
+                    *weaknesses.entry(&dr.name).or_default() += DamageMultiplicator::Double;
+                }
+
+                for dr in &damage_relations.no_damage_from {
+                    *weaknesses.entry(&dr.name).or_default() += DamageMultiplicator::Immune;
+                }
             }
 
-            for dr in damage_relations.no_damage_from {
-                *weaknesses.entry(dr.name).or_default() += DamageMultiplicator::Immune;
+            let mut type_weaknesses = Vec::new();
+            for type_id in utils::get_type_ids() {
+                if generation_id(&types[&type_id].generation.name)? > generation.id {
+                    continue;
+                }
+
+                let damage_multiplicator = weaknesses.get(&type_id).copied().unwrap_or_default();
+                type_weaknesses.push(Weakness {
+                    type_id,
+                    damage_multiplicator,
+                    factor: damage_multiplicator.factor(),
+                });
+            }
+
+            let mut variants = Vec::new();
+            for (ability_id, ability_name, first_generation_id, effects) in &abilities {
+                if *first_generation_id > generation.id {
+                    continue;
+                }
+
+                variants.push(WeaknessesVariant {
+                    ability_id: Some(ability_id.clone()),
+                    ability_name: Some(ability_name.clone()),
+                    weaknesses: type_weaknesses
+                        .iter()
+                        .map(|weakness| {
+                            let factor = effects.iter().fold(weakness.factor, |factor, effect| {
+                                effect.apply(&weakness.type_id, factor)
+                            });
+                            Weakness {
+                                type_id: weakness.type_id.clone(),
+                                damage_multiplicator: DamageMultiplicator::closest(factor),
+                                factor,
+                            }
+                        })
+                        .collect(),
+                });
+            }
+
+            variants.insert(
+                0,
+                WeaknessesVariant {
+                    ability_id: None,
+                    ability_name: None,
+                    weaknesses: type_weaknesses,
+                },
+            );
+
+            match generations_weaknesses.last_mut() {
+                Some(previous) if previous.variants == variants => {
+                    previous.generations_name =
+                        format!("{} – {}", last_generation_name, generation.localized_name);
+                }
+                _ => {
+                    last_generation_name = generation.localized_name.clone();
+                    generations_weaknesses.push(GenerationsWeaknesses {
+                        first_generation_id: generation.name.clone(),
+                        generations_name: generation.localized_name.clone(),
+                        variants,
+                    })
+                }
             }
         }
 
-        Ok(Self(weaknesses))
+        // The most recent generations first, as they are the default.
+        generations_weaknesses.reverse();
+
+        Ok(Self {
+            generations: generations_weaknesses,
+        })
+    }
+}
+
+/// Returns the generations from the one the species was introduced in.
+fn species_generations(
+    generations: &[Generation],
+    species_generation_id: i64,
+) -> impl Iterator<Item = &Generation> {
+    generations
+        .iter()
+        .filter(move |generation| generation.id >= species_generation_id)
+}
+
+/// Returns the first generation an ability introduced in `ability_generation_id` applies to,
+/// hidden abilities not existing before their own generation.
+fn ability_first_generation_id(ability_generation_id: i64, is_hidden: bool) -> i64 {
+    if is_hidden {
+        ability_generation_id.max(HIDDEN_ABILITIES_GENERATION)
+    } else {
+        ability_generation_id
+    }
+}
+
+/// Returns the value in effect during `generation_id`, such as the types of a Pokemon:
+/// `current`, unless one of the `past` values, which apply up to and including their generation,
+/// still applies. The earliest applying past value wins.
+fn value_at_generation<'a, T>(current: &'a T, past: &[(i64, &'a T)], generation_id: i64) -> &'a T {
+    past.iter()
+        .filter(|(past_generation_id, _)| *past_generation_id >= generation_id)
+        .min_by_key(|(past_generation_id, _)| *past_generation_id)
+        .map_or(current, |(_, value)| value)
+}
+
+/// Returns all the generations, from the first one.
+async fn get_generations(rc: &RustemonClient, lang: &Lang) -> Result<Vec<Generation>> {
+    let mut generations = Vec::new();
+
+    for generation in rustemon::games::generation::get_page(rc).await?.results {
+        let generation = generation.follow(rc).await?;
+        generations.push(Generation {
+            id: generation.id,
+            localized_name: generation
+                .names
+                .find_by_lang(lang)
+                .unwrap_or_else(|| generation.name.clone()),
+            name: generation.name,
+        });
+    }
+    generations.sort_by_key(|generation| generation.id);
+
+    Ok(generations)
+}
+
+/// Returns the multiplicator for each type, in the `utils::get_type_ids` order,
+/// from the types dealt or taking double, half and no damage by a single type.
+pub(crate) fn damage_multiplicators(
+    double_damage: &[NamedApiResource<Type>],
+    half_damage: &[NamedApiResource<Type>],
+    no_damage: &[NamedApiResource<Type>],
+) -> Vec<(String, DamageMultiplicator)> {
+    let contains = |types: &[NamedApiResource<Type>], type_id: &str| {
+        types.iter().any(|type_| type_.name == type_id)
+    };
+
+    utils::get_type_ids()
+        .into_iter()
+        .map(|type_id| {
+            let damage_multiplicator = if contains(no_damage, &type_id) {
+                DamageMultiplicator::Immune
+            } else if contains(half_damage, &type_id) {
+                DamageMultiplicator::Half
+            } else if contains(double_damage, &type_id) {
+                DamageMultiplicator::Double
+            } else {
+                DamageMultiplicator::Simple
+            };
+            (type_id, damage_multiplicator)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generations() -> Vec<Generation> {
+        (1..=9)
+            .map(|id| Generation {
+                id,
+                name: format!("generation-{}", id),
+                localized_name: format!("Generation {}", id),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn species_generations_start_at_the_species_one() {
+        let generations = generations();
+
+        let ids = |species_generation_id| {
+            species_generations(&generations, species_generation_id)
+                .map(|generation| generation.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(1), (1..=9).collect::<Vec<_>>());
+        assert_eq!(ids(4), (4..=9).collect::<Vec<_>>());
+        assert_eq!(ids(9), [9]);
+    }
+
+    #[test]
+    fn hidden_abilities_start_at_generation_5() {
+        assert_eq!(ability_first_generation_id(3, false), 3);
+        assert_eq!(ability_first_generation_id(3, true), 5);
+        assert_eq!(ability_first_generation_id(7, true), 7);
+    }
+
+    #[test]
+    fn past_values_apply_up_to_their_generation() {
+        // Like Clefairy, Normal up to generation 5 and Fairy since.
+        let past = [(5, &"normal")];
+        assert_eq!(*value_at_generation(&"fairy", &past, 1), "normal");
+        assert_eq!(*value_at_generation(&"fairy", &past, 5), "normal");
+        assert_eq!(*value_at_generation(&"fairy", &past, 6), "fairy");
+
+        let past = [(5, &"second"), (1, &"first")];
+        assert_eq!(*value_at_generation(&"current", &past, 1), "first");
+        assert_eq!(*value_at_generation(&"current", &past, 2), "second");
+        assert_eq!(*value_at_generation(&"current", &past, 9), "current");
+        assert_eq!(*value_at_generation(&"current", &[], 1), "current");
     }
 }
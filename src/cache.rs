@@ -0,0 +1,60 @@
+use std::{
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use rustemon::client::{CacheMode, RustemonClient};
+
+/// Builds a `RustemonClient` backed by an on-disk cache stored in `cache_dir`.
+///
+/// Every PokeAPI resource is stored under its URL, so any builder following
+/// the same resource hits the cache instead of the network.
+/// Entries older than `ttl` are pruned before the client is created.
+/// When `offline` is set, a missing entry is an error instead of a network call.
+pub(crate) fn build_client(
+    cache_dir: &Path,
+    ttl: Option<Duration>,
+    offline: bool,
+) -> Result<RustemonClient> {
+    if let Some(ttl) = ttl {
+        let pruned = prune_expired_entries(cache_dir, ttl)?;
+        if pruned > 0 {
+            println!("{} expired cache entries pruned", pruned);
+        }
+    }
+
+    let cache_path = cache_dir
+        .to_str()
+        .with_context(|| format!("Cache directory {:?} is not valid UTF-8", cache_dir))?
+        .to_owned();
+    let cache_mode = if offline {
+        CacheMode::OnlyIfCached
+    } else {
+        CacheMode::ForceCache
+    };
+
+    Ok(RustemonClient::new_path_unchecked(
+        cache_path, cache_mode, None,
+    ))
+}
+
+fn prune_expired_entries(cache_dir: &Path, ttl: Duration) -> Result<usize> {
+    if !cache_dir.exists() {
+        return Ok(0);
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    let expired_keys = cacache::list_sync(cache_dir)
+        .filter_map(|metadata| metadata.ok())
+        .filter(|metadata| now.saturating_sub(metadata.time) > ttl.as_millis())
+        .map(|metadata| metadata.key)
+        .collect::<Vec<_>>();
+
+    for key in &expired_keys {
+        cacache::remove_sync(cache_dir, key)
+            .with_context(|| format!("Cannot remove cache entry {}", key))?;
+    }
+
+    Ok(expired_keys.len())
+}
@@ -1,32 +1,113 @@
-use anyhow::Result;
+use std::{
+    collections::BTreeMap,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::{Context as _, Result};
 use handlebars::{handlebars_helper, Handlebars};
+use include_dir::DirEntry;
 use num_format::{Locale, ToFormattedString};
 use rustemon::client::RustemonClient;
 
-use crate::builders::weaknesses::DamageMultiplicator;
+use crate::{
+    args::Args, builders::weaknesses::DamageMultiplicator, cache, find_by_lang::Lang,
+    manifest::Manifest, report::Report,
+};
 
 pub(crate) struct Context<'a> {
     hb: Handlebars<'a>,
     rc: RustemonClient,
-    lang: String,
+    langs: Vec<Lang>,
+    manifest: Option<Manifest>,
+    json: bool,
+    base_url: Option<String>,
+    report: Report,
 }
 
+const TEMPLATES: include_dir::Dir = include_dir::include_dir!("./templates");
+
 const SPLITTER_SRC: &str = include_str!("../scripts/splitter.rhai");
 
 handlebars_helper!(ff32: |number: f32| format!("{:09.3}", number.to_string()));
 
 handlebars_helper!(pretty_i64: |number: i64| number.to_formatted_string(&Locale::fr));
 
-handlebars_helper!(damage_multiplicator_to_f32: |damage_multiplicator: DamageMultiplicator| match damage_multiplicator {
-    DamageMultiplicator::Immune => 0.0,
-    DamageMultiplicator::Quarter => 0.25,
-    DamageMultiplicator::Half => 0.5,
-    DamageMultiplicator::Simple => 1.0,
-    DamageMultiplicator::Double => 2.0,
-    DamageMultiplicator::Quadruple => 4.0,
-});
+handlebars_helper!(damage_multiplicator_to_f32: |damage_multiplicator: DamageMultiplicator| damage_multiplicator.factor());
+
+/// Loads the embedded templates, overlaid by the ones found in `templates_override`, by name.
+fn load_templates(templates_override: Option<&Path>) -> Result<BTreeMap<String, String>> {
+    let mut templates = BTreeMap::new();
+    load_embedded_templates(TEMPLATES.entries(), &mut templates)?;
+
+    if let Some(templates_override) = templates_override {
+        load_templates_directory(templates_override, templates_override, &mut templates)
+            .with_context(|| {
+                format!(
+                    "Cannot load templates from {}",
+                    templates_override.display()
+                )
+            })?;
+    }
+
+    Ok(templates)
+}
+
+fn load_embedded_templates(
+    entries: &[DirEntry],
+    templates: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    for entry in entries {
+        match entry {
+            DirEntry::File(file) => {
+                if let Some(template_name) = template_name(file.path()) {
+                    let template_source = file
+                        .contents_utf8()
+                        .with_context(|| format!("Template {} is not UTF-8", template_name))?;
+                    templates.insert(template_name, template_source.to_owned());
+                }
+            }
+            DirEntry::Dir(dir) => {
+                load_embedded_templates(dir.entries(), templates)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn load_templates_directory(
+    templates_path: &Path,
+    dir: &Path,
+    templates: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            load_templates_directory(templates_path, &path, templates)?;
+        } else if let Some(template_name) = template_name(path.strip_prefix(templates_path)?) {
+            templates.insert(template_name, read_to_string(&path)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the name of the template at `relative_path`, such as `partials/card`, if it is one.
+fn template_name(relative_path: &Path) -> Option<String> {
+    relative_path
+        .extension()
+        .is_some_and(|extension| extension == "hbs")
+        .then(|| {
+            relative_path
+                .with_extension("")
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+}
 
-fn init_handlebars() -> Result<Handlebars<'static>> {
+fn init_handlebars(templates: &BTreeMap<String, String>) -> Result<Handlebars<'static>> {
     let mut hb = Handlebars::new();
     hb.set_strict_mode(true);
 
@@ -39,18 +120,44 @@ fn init_handlebars() -> Result<Handlebars<'static>> {
 
     hb.register_script_helper("splitter", SPLITTER_SRC)?;
 
-    hb.register_templates_directory(".hbs", "templates/")?;
+    for (template_name, template_source) in templates {
+        hb.register_template_string(template_name, template_source)
+            .with_context(|| format!("Invalid template {}", template_name))?;
+    }
 
     Ok(hb)
 }
 
 impl<'a> Context<'a> {
-    pub fn try_new() -> Result<Self> {
-        let hb = init_handlebars()?;
-        let rc = RustemonClient::default();
-        let lang = "en".to_string();
-
-        Ok(Self { hb, rc, lang })
+    pub fn try_new(args: &Args) -> Result<Self> {
+        let templates = load_templates(args.templates.as_deref())?;
+        let hb = init_handlebars(&templates)?;
+        let rc = cache::build_client(
+            &args.cache_dir,
+            args.cache_ttl.map(Duration::from_secs),
+            args.offline,
+        )?;
+        let langs = args
+            .lang
+            .iter()
+            .map(|lang| Lang::new(lang.clone(), args.fallback_lang.clone()))
+            .collect();
+
+        let manifest = if args.incremental {
+            Some(Manifest::load(&args.path, &templates)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            hb,
+            rc,
+            langs,
+            manifest,
+            json: args.json,
+            base_url: normalize_base_url(&args.base_url),
+            report: Report::default(),
+        })
     }
 
     pub fn hb(&self) -> &Handlebars<'_> {
@@ -61,7 +168,35 @@ impl<'a> Context<'a> {
         &self.rc
     }
 
-    pub fn lang(&self) -> &String {
-        &self.lang
+    pub fn langs(&self) -> &[Lang] {
+        &self.langs
+    }
+
+    pub fn manifest(&self) -> Option<&Manifest> {
+        self.manifest.as_ref()
+    }
+
+    pub fn json(&self) -> bool {
+        self.json
+    }
+
+    pub fn report(&self) -> &Report {
+        &self.report
+    }
+
+    /// Returns the URL prefix of the site root, as seen from the page at `page_path`.
+    pub fn base_url(&self, page_path: &Path) -> String {
+        match &self.base_url {
+            Some(base_url) => base_url.clone(),
+            None => "../".repeat(page_path.components().count().saturating_sub(1)),
+        }
+    }
+}
+
+/// Returns `base_url` ending with a slash, or `None` if links should be relative.
+pub(crate) fn normalize_base_url(base_url: &str) -> Option<String> {
+    match base_url.trim_end_matches('/') {
+        "." => None,
+        base_url => Some(format!("{}/", base_url)),
     }
 }
@@ -1,26 +1,94 @@
-use std::iter::Iterator;
+use std::{fmt::Display, iter::Iterator};
 
-use rustemon::model::{pokemon::Genus, resource::Name};
+use rustemon::model::{
+    pokemon::Genus,
+    resource::{Description, Name, VerboseEffect},
+};
+
+/// A language to look wordings up in, followed by the languages to fall back on,
+/// by order of preference.
+#[derive(Clone, Debug)]
+pub(crate) struct Lang {
+    chain: Vec<String>,
+}
+
+impl Lang {
+    pub fn new(lang: String, fallbacks: Vec<String>) -> Self {
+        let mut chain = Vec::with_capacity(fallbacks.len() + 1);
+        chain.push(lang);
+        for fallback in fallbacks {
+            if !chain.contains(&fallback) {
+                chain.push(fallback);
+            }
+        }
+
+        Self { chain }
+    }
+
+    /// Returns the preferred language, without its fallbacks.
+    pub fn id(&self) -> &str {
+        &self.chain[0]
+    }
+
+    /// Returns the preferred language followed by its fallbacks.
+    pub fn ids(&self) -> &[String] {
+        &self.chain
+    }
+
+    fn find_first<'a, T>(
+        &self,
+        wordings: &'a [T],
+        language_of: impl Fn(&T) -> &str,
+    ) -> Option<&'a T> {
+        self.chain
+            .iter()
+            .find_map(|lang| wordings.iter().find(|wording| language_of(wording) == lang))
+    }
+}
+
+impl From<&str> for Lang {
+    fn from(lang: &str) -> Self {
+        Self::new(lang.to_owned(), vec![])
+    }
+}
+
+impl Display for Lang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chain.join("/"))
+    }
+}
 
 pub(crate) trait FindWordingByLang
 where
     Self: IntoIterator,
 {
-    fn find_by_lang(&self, lang: &str) -> Option<String>;
+    fn find_by_lang(&self, lang: &Lang) -> Option<String>;
 }
 
 impl FindWordingByLang for Vec<Genus> {
-    fn find_by_lang(&self, lang: &str) -> Option<String> {
-        self.iter()
-            .find(|genus| genus.language.name == lang)
+    fn find_by_lang(&self, lang: &Lang) -> Option<String> {
+        lang.find_first(self, |genus| &genus.language.name)
             .map(|genus| genus.genus.clone())
     }
 }
 
 impl FindWordingByLang for Vec<Name> {
-    fn find_by_lang(&self, lang: &str) -> Option<String> {
-        self.iter()
-            .find(|name| name.language.name == lang)
+    fn find_by_lang(&self, lang: &Lang) -> Option<String> {
+        lang.find_first(self, |name| &name.language.name)
             .map(|name| name.name.clone())
     }
 }
+
+impl FindWordingByLang for Vec<VerboseEffect> {
+    fn find_by_lang(&self, lang: &Lang) -> Option<String> {
+        lang.find_first(self, |verbose_effect| &verbose_effect.language.name)
+            .map(|verbose_effect| verbose_effect.effect.clone())
+    }
+}
+
+impl FindWordingByLang for Vec<Description> {
+    fn find_by_lang(&self, lang: &Lang) -> Option<String> {
+        lang.find_first(self, |description| &description.language.name)
+            .map(|description| description.description.clone())
+    }
+}
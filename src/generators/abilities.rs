@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        ability_detail::{self, AbilityDetail},
+        all_pokemon::GeneratedPokemon,
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::generate_element_pages;
+
+/// Generates a page for every ability of the generated Pokemon.
+pub(super) async fn generate_ability_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let ability_holders = ability_detail::collect_ability_holders(generated_pokemons);
+
+    generate_element_pages::<_, AbilityDetail>(
+        "abilities",
+        "ability",
+        &ability_holders,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    Ok(())
+}
@@ -1,20 +1,42 @@
-use std::path::PathBuf;
+use std::path::Path;
 
 use crate::{
-    builders::{all_pokemon::AllPokemon, Builder},
+    builders::{
+        all_pokemon::{AllPokemon, GeneratedPokemon},
+        Builder,
+    },
     context::Context,
+    find_by_lang::Lang,
 };
 
-use super::render_to_write;
+use super::{render_to_write, write_json};
 
 use anyhow::Result;
 
 pub(super) async fn generate_all_pokemon_page(
-    mut path: PathBuf,
-    pokemon_id_and_path: &Vec<(String, PathBuf)>,
+    base_path: &Path,
+    generated_pokemons: &Vec<GeneratedPokemon>,
+    lang: &Lang,
     context: &Context<'_>,
 ) -> Result<()> {
-    let all_pokemon = &AllPokemon::build(pokemon_id_and_path, context.rc(), context.lang()).await?;
-    path.push("all_pokemon.html");
-    render_to_write(context.hb(), "all_pokemon", all_pokemon, &path).await
+    let all_pokemon = &AllPokemon::build(generated_pokemons, context.rc(), lang).await?;
+
+    if context.json() {
+        write_json(
+            all_pokemon,
+            base_path,
+            Path::new("pokemons/index.json"),
+            lang,
+        )?;
+    }
+
+    render_to_write(
+        context,
+        "all_pokemon",
+        all_pokemon,
+        base_path,
+        Path::new("all_pokemon.html"),
+        lang,
+    )
+    .await
 }
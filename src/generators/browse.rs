@@ -0,0 +1,56 @@
+use std::{fs::create_dir_all, path::Path};
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        browse::{self, Browse, BrowseGroup},
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::{generate_element_pages, render_to_write, write_json};
+
+/// Generates a page for every generation, status, habitat, color and shape
+/// of the generated Pokemon, and the index of these pages.
+pub(super) async fn generate_browse_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let browse_members =
+        browse::collect_browse_members(generated_pokemons, context.rc(), lang).await?;
+
+    let browse = &Browse::new(&browse_members);
+
+    create_dir_all(base_path.join(lang.id()).join("browse"))?;
+
+    generate_element_pages::<_, BrowseGroup>(
+        "browse",
+        "browse_group",
+        &browse_members,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    if context.json() {
+        write_json(browse, base_path, Path::new("browse/index.json"), lang)?;
+    }
+
+    render_to_write(
+        context,
+        "browse",
+        browse,
+        base_path,
+        Path::new("browse.html"),
+        lang,
+    )
+    .await
+}
@@ -1,12 +1,39 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, fs::File, path::Path};
 
 use anyhow::Result;
 use handlebars::JsonValue;
 
 use super::render_to_write;
-use crate::context::Context;
+use crate::{context::Context, find_by_lang::Lang};
 
-pub(super) async fn generate_home_page(mut path: PathBuf, context: &Context<'_>) -> Result<()> {
-    path.push("home.html");
-    render_to_write(context.hb(), "home", &JsonValue::Null, &path).await
+pub(super) async fn generate_home_page(
+    base_path: &Path,
+    lang: &Lang,
+    context: &Context<'_>,
+) -> Result<()> {
+    render_to_write(
+        context,
+        "home",
+        &JsonValue::Null,
+        base_path,
+        Path::new("home.html"),
+        lang,
+    )
+    .await
+}
+
+/// Generates the root home page, redirecting to the home page in `lang`.
+pub(super) async fn generate_home_redirect(
+    base_path: &Path,
+    lang: &Lang,
+    context: &Context<'_>,
+) -> Result<()> {
+    let mut file = File::create(base_path.join("home.html"))?;
+    let target = format!("{}/home.html", lang.id());
+
+    context
+        .hb()
+        .render_to_write("redirect", &BTreeMap::from([("target", target)]), &mut file)?;
+
+    Ok(())
 }
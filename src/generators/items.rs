@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        item_detail::{self, ItemDetail},
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::generate_element_pages;
+
+/// Generates a page for every item held by the generated Pokemon.
+pub(super) async fn generate_item_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let item_holders = item_detail::collect_item_holders(generated_pokemons);
+
+    generate_element_pages::<_, ItemDetail>(
+        "items",
+        "item",
+        &item_holders,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    Ok(())
+}
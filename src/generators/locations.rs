@@ -0,0 +1,62 @@
+use std::{fs::create_dir_all, path::Path};
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        location_detail::{self, LocationDetail},
+        regions::Regions,
+        Builder, Element,
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::{generate_element_pages, render_to_write, write_json};
+
+/// Generates a page for every location where the generated Pokemon can be encountered,
+/// and the index of these locations by region.
+pub(super) async fn generate_location_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let generated_locations =
+        location_detail::collect_generated_locations(generated_pokemons, context.rc()).await?;
+
+    create_dir_all(base_path.join(lang.id()).join("locations"))?;
+
+    generate_element_pages::<_, LocationDetail>(
+        "locations",
+        "location",
+        &generated_locations,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    let location_ids = generated_locations
+        .iter()
+        .map(|generated_location| generated_location.element_id().to_owned())
+        .collect();
+    let regions = &Regions::build(&location_ids, context.rc(), lang).await?;
+
+    if context.json() {
+        write_json(regions, base_path, Path::new("locations/index.json"), lang)?;
+    }
+
+    render_to_write(
+        context,
+        "regions",
+        regions,
+        base_path,
+        Path::new("regions.html"),
+        lang,
+    )
+    .await
+}
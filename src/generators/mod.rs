@@ -1,97 +1,341 @@
-use anyhow::Result;
-use handlebars::Handlebars;
+use anyhow::{Context as _, Result};
+use futures::{stream, StreamExt};
 use indicatif::ProgressBar;
-use rustemon::{client::RustemonClient, Follow};
 use serde::Serialize;
 
-use std::path::PathBuf;
+use std::{
+    collections::BTreeSet,
+    fs::create_dir_all,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use crate::context::Context;
+use crate::{
+    args::Selection,
+    builders::{Builder, Element},
+    context::Context,
+    find_by_lang::Lang,
+};
 
+mod abilities;
 mod all_pokemon;
+mod browse;
 mod home;
+mod items;
+mod locations;
+mod moves;
+mod pokedexes;
 mod pokemon;
 mod search;
+mod selection;
+mod types;
+
+pub(crate) async fn generate(
+    base_path: PathBuf,
+    jobs: usize,
+    selection: &Selection,
+    context: &Context<'_>,
+) -> Result<()> {
+    check_langs(context).await?;
 
-pub(crate) async fn generate(base_path: PathBuf, context: &Context<'_>) -> Result<()> {
     println!("Fetching PokeAPI to count Pokemons to generate");
-    let pokemon_names = generate_pokemon_list(context.rc()).await?;
+    let pokemon_names = selection::select_pokemon_names(selection, jobs, context.rc()).await?;
     println!("{} Pokemons found", pokemon_names.len());
 
-    let mut generated_pokemons = Vec::with_capacity(pokemon_names.len());
+    for lang in context.langs() {
+        println!("Starting all pages generation in {}", lang.id());
+        generate_lang(&base_path, &pokemon_names, lang, jobs, context).await?;
+    }
+
+    println!("Generating home page redirection");
+    home::generate_home_redirect(&base_path, &context.langs()[0], context).await?;
+    println!("Home page redirection generated");
 
-    println!("Starting all pages generation");
-    println!("Starting generation for Pokemons");
-    let pg = ProgressBar::new(pokemon_names.len() as u64);
-    for pokemon_name in &pokemon_names {
-        pg.println(&format!("Generating page for {}", pokemon_name));
-        generated_pokemons
-            .push(pokemon::generate_pokemon_page(base_path.clone(), pokemon_name, context).await?);
-        pg.println(&format!("Generated page for {}", pokemon_name));
-        pg.inc(1);
+    if let Some(manifest) = context.manifest() {
+        manifest.save(&base_path)?;
     }
+
+    context.report().save(&base_path)?;
+    println!(
+        "{} failures reported in {}",
+        context.report().len(),
+        base_path.join("report.txt").display()
+    );
+
+    Ok(())
+}
+
+async fn generate_lang(
+    base_path: &Path,
+    pokemon_names: &[String],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    create_dir_all(base_path.join(lang.id()).join("pokemons"))?;
+
+    let selected_pokemon_ids = &Arc::new(pokemon_names.iter().cloned().collect::<BTreeSet<_>>());
+
+    println!("Starting generation for Pokemons with {} jobs", jobs);
+    let pg = ProgressBar::new(pokemon_names.len() as u64);
+    let generated_pokemons: Vec<_> = stream::iter(pokemon_names)
+        .map(|pokemon_name| {
+            let pg = &pg;
+            async move {
+                pg.println(format!("Generating page for {}", pokemon_name));
+                let generated_pokemon = match pokemon::generate_pokemon_page(
+                    base_path,
+                    pokemon_name,
+                    selected_pokemon_ids,
+                    lang,
+                    context,
+                )
+                .await
+                {
+                    Ok(generated_pokemon) => {
+                        pg.println(format!("Generated page for {}", pokemon_name));
+                        Some(generated_pokemon)
+                    }
+                    Err(error) => {
+                        pg.println(format!(
+                            "Failed to generate page for {}: {:#}",
+                            pokemon_name, error
+                        ));
+                        context
+                            .report()
+                            .record(lang.id(), pokemon_name, None, &error);
+                        None
+                    }
+                };
+                pg.inc(1);
+                generated_pokemon
+            }
+        })
+        .buffered(jobs)
+        .filter_map(|generated_pokemon| async move { generated_pokemon })
+        .collect()
+        .await;
+    pg.finish();
     println!("Pokemon pages generated");
 
     println!("Generating search page");
-    search::generate_search_page(base_path.clone(), &generated_pokemons, context).await?;
-    println!("Search page generated");
+    let search_page =
+        search::generate_search_page(base_path, &generated_pokemons, lang, context).await;
+    report_page(search_page, "search", lang, context);
 
     println!("Generating all Pokemons page");
-    all_pokemon::generate_all_pokemon_page(base_path.clone(), &generated_pokemons, context).await?;
-    println!("All Pokemons page generated");
+    let all_pokemon_page =
+        all_pokemon::generate_all_pokemon_page(base_path, &generated_pokemons, lang, context).await;
+    report_page(all_pokemon_page, "all Pokemons", lang, context);
+
+    println!("Generating move pages");
+    let move_pages =
+        moves::generate_move_pages(base_path, &generated_pokemons, lang, jobs, context).await;
+    report_page(move_pages, "moves", lang, context);
+
+    println!("Generating ability pages");
+    let ability_pages =
+        abilities::generate_ability_pages(base_path, &generated_pokemons, lang, jobs, context)
+            .await;
+    report_page(ability_pages, "abilities", lang, context);
+
+    println!("Generating type pages");
+    let type_pages =
+        types::generate_type_pages(base_path, &generated_pokemons, lang, jobs, context).await;
+    report_page(type_pages, "types", lang, context);
+
+    println!("Generating type chart page");
+    let type_chart_page = types::generate_type_chart_page(base_path, lang, context).await;
+    report_page(type_chart_page, "type chart", lang, context);
+
+    println!("Generating location pages");
+    let location_pages =
+        locations::generate_location_pages(base_path, &generated_pokemons, lang, jobs, context)
+            .await;
+    report_page(location_pages, "locations", lang, context);
+
+    println!("Generating item pages");
+    let item_pages =
+        items::generate_item_pages(base_path, &generated_pokemons, lang, jobs, context).await;
+    report_page(item_pages, "items", lang, context);
+
+    println!("Generating Pokedex pages");
+    let pokedex_pages =
+        pokedexes::generate_pokedex_pages(base_path, &generated_pokemons, lang, jobs, context)
+            .await;
+    report_page(pokedex_pages, "pokedexes", lang, context);
+
+    println!("Generating browse pages");
+    let browse_pages =
+        browse::generate_browse_pages(base_path, &generated_pokemons, lang, jobs, context).await;
+    report_page(browse_pages, "browse", lang, context);
 
     println!("Generating home page");
-    home::generate_home_page(base_path, context).await?;
-    println!("Home page generated");
+    let home_page = home::generate_home_page(base_path, lang, context).await;
+    report_page(home_page, "home", lang, context);
 
     Ok(())
 }
 
-async fn generate_pokemon_list(rc: &RustemonClient) -> Result<Vec<String>> {
-    let nb_pokemon = rustemon::pokemon::pokemon::get_page(rc).await?.count;
+/// Generates the page of each of `elements` in `directory` with `jobs` concurrent jobs,
+/// from the `inner_template` rendering of the `D` built for the element.
+/// A failing page is recorded in the report as `<directory>/<id>` without stopping the others.
+async fn generate_element_pages<T, D>(
+    directory: &str,
+    inner_template: &str,
+    elements: &[T],
+    base_path: &Path,
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) where
+    T: Element + Sync,
+    D: Builder<T> + Serialize,
+{
+    let pg = ProgressBar::new(elements.len() as u64);
+    stream::iter(elements)
+        .map(|element| {
+            let pg = &pg;
+            async move {
+                let id = element.element_id();
+                let page = generate_element_page::<T, D>(
+                    directory,
+                    inner_template,
+                    element,
+                    base_path,
+                    lang,
+                    context,
+                )
+                .await;
+                if let Err(error) = page {
+                    pg.println(format!(
+                        "Failed to generate page {}/{}: {:#}",
+                        directory, id, error
+                    ));
+                    context.report().record(
+                        lang.id(),
+                        &format!("{}/{}", directory, id),
+                        None,
+                        &error,
+                    );
+                }
+                pg.inc(1);
+            }
+        })
+        .buffered(jobs)
+        .collect::<()>()
+        .await;
+    pg.finish();
+}
+
+async fn generate_element_page<T, D>(
+    directory: &str,
+    inner_template: &str,
+    element: &T,
+    base_path: &Path,
+    lang: &Lang,
+    context: &Context<'_>,
+) -> Result<()>
+where
+    T: Element + Sync,
+    D: Builder<T> + Serialize,
+{
+    let element_path = format!("{}/{}", directory, element.element_id());
+    let page_path = PathBuf::from(format!("{}.html", element_path));
+    let data = D::build(element, context.rc(), lang).await?;
 
-    let mut pokemon_names = Vec::with_capacity(nb_pokemon as usize);
+    if let Some(page_directory) = page_path.parent() {
+        create_dir_all(base_path.join(lang.id()).join(page_directory))?;
+    }
 
-    let mut offset = 0;
-    while offset < nb_pokemon {
-        let page = rustemon::pokemon::pokemon::get_page_with_param(offset, 100, rc).await?;
+    render_to_write(context, inner_template, &data, base_path, &page_path, lang).await?;
 
-        for p in page.results.into_iter() {
-            if p.follow(rc).await?.is_default {
-                pokemon_names.push(p.name);
-            }
+    if context.json() {
+        let data_path = PathBuf::from(format!("{}.json", element_path));
+        write_json(&data, base_path, &data_path, lang)?;
+    }
+
+    Ok(())
+}
+
+/// Records the failure of the `page` generation in the report, instead of aborting the run.
+fn report_page(result: Result<()>, page: &str, lang: &Lang, context: &Context<'_>) {
+    match result {
+        Ok(()) => println!("{} page generated", page),
+        Err(error) => {
+            println!("Failed to generate {} page: {:#}", page, error);
+            context.report().record(lang.id(), page, None, &error);
         }
-        offset += 100;
     }
+}
 
-    #[cfg(debug_assertions)]
-    pokemon_names.truncate(10);
+async fn check_langs(context: &Context<'_>) -> Result<()> {
+    for lang in context.langs().iter().flat_map(Lang::ids) {
+        rustemon::utility::language::get_by_name(lang, context.rc())
+            .await
+            .with_context(|| format!("Unknown language {}", lang))?;
+    }
 
-    Ok(pokemon_names)
+    Ok(())
 }
 
 #[derive(Serialize)]
 struct BaseContext<'a, T: Serialize> {
     inner_template: &'a str,
+    base_url: String,
+    lang: &'a str,
+    langs: Vec<&'a str>,
+    page_path: &'a Path,
     data: T,
 }
 
-pub(self) async fn render_to_write<T>(
-    hb: &Handlebars<'_>,
+/// Renders `inner_template` inside the base layout, to `page_path` in the `lang` subtree of `base_path`.
+/// In incremental mode, the page is left untouched if its inputs didn't change since the previous run.
+async fn render_to_write<T>(
+    context: &Context<'_>,
     inner_template: &str,
     data: &T,
-    file_path: &PathBuf,
+    base_path: &Path,
+    page_path: &Path,
+    lang: &Lang,
 ) -> Result<()>
 where
     T: Serialize,
 {
-    let mut file = std::fs::File::create(file_path)?;
-    let context = &BaseContext {
+    let site_path = Path::new(lang.id()).join(page_path);
+    let file_path = base_path.join(&site_path);
+    let base_context = &BaseContext {
         inner_template,
+        base_url: context.base_url(&site_path),
+        lang: lang.id(),
+        langs: context.langs().iter().map(Lang::id).collect(),
+        page_path,
         data,
     };
 
-    hb.render_to_write("base", context, &mut file)?;
+    if let Some(manifest) = context.manifest() {
+        let page_hash = manifest.page_hash(inner_template, base_context)?;
+        let is_up_to_date = manifest.record(&site_path, page_hash);
+        if is_up_to_date && file_path.exists() {
+            return Ok(());
+        }
+    }
+
+    let mut file = std::fs::File::create(file_path)?;
+    context
+        .hb()
+        .render_to_write("base", base_context, &mut file)?;
+
+    Ok(())
+}
+
+/// Writes `data` as JSON to `data_path` in the `lang` subtree of `base_path`.
+fn write_json<T>(data: &T, base_path: &Path, data_path: &Path, lang: &Lang) -> Result<()>
+where
+    T: Serialize,
+{
+    let file = std::fs::File::create(base_path.join(lang.id()).join(data_path))?;
+    serde_json::to_writer(file, data)?;
 
     Ok(())
 }
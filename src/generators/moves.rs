@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        move_detail::{self, MoveDetail},
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::generate_element_pages;
+
+/// Generates a page for every move learned by the generated Pokemon.
+pub(super) async fn generate_move_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let move_learners = move_detail::collect_move_learners(generated_pokemons);
+
+    generate_element_pages::<_, MoveDetail>(
+        "moves",
+        "move",
+        &move_learners,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::{fs::create_dir_all, path::Path};
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        pokedex_detail::{self, PokedexDetail, Pokedexes},
+        Builder, Element,
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::{generate_element_pages, render_to_write, write_json};
+
+/// Generates a page for every regional Pokedex listing generated Pokemon,
+/// and the index of these Pokedexes.
+pub(super) async fn generate_pokedex_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let pokedex_members = pokedex_detail::collect_pokedex_members(generated_pokemons);
+
+    create_dir_all(base_path.join(lang.id()).join("pokedexes"))?;
+
+    generate_element_pages::<_, PokedexDetail>(
+        "pokedexes",
+        "pokedex",
+        &pokedex_members,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    let pokedex_ids = pokedex_members
+        .iter()
+        .map(|pokedex_members| pokedex_members.element_id().to_owned())
+        .collect();
+    let pokedexes = &Pokedexes::build(&pokedex_ids, context.rc(), lang).await?;
+
+    if context.json() {
+        write_json(
+            pokedexes,
+            base_path,
+            Path::new("pokedexes/index.json"),
+            lang,
+        )?;
+    }
+
+    render_to_write(
+        context,
+        "pokedexes",
+        pokedexes,
+        base_path,
+        Path::new("pokedexes.html"),
+        lang,
+    )
+    .await
+}
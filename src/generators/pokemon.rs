@@ -1,24 +1,53 @@
-use std::path::PathBuf;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::{
-    builders::{pokemon::Pokemon, Builder},
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        pokemon::{Pokemon, SelectedPokemon},
+        Builder,
+    },
     context::Context,
+    find_by_lang::Lang,
 };
 use anyhow::Result;
 
-use super::render_to_write;
+use super::{render_to_write, write_json};
 
 pub(super) async fn generate_pokemon_page(
-    mut path: PathBuf,
+    base_path: &Path,
     pokemon_id: &String,
+    selected_pokemon_ids: &Arc<BTreeSet<String>>,
+    lang: &Lang,
     context: &Context<'_>,
-) -> Result<(String, PathBuf)> {
+) -> Result<GeneratedPokemon> {
     let relative_path = PathBuf::from(format!("pokemons/{}.html", pokemon_id));
-    path.push(&relative_path);
 
-    let pokemon = Pokemon::build(pokemon_id, context.rc(), context.lang()).await?;
+    let selected_pokemon = SelectedPokemon::new(pokemon_id.clone(), selected_pokemon_ids.clone());
+    let mut pokemon = Pokemon::build(&selected_pokemon, context.rc(), lang).await?;
+    for (section, error) in pokemon.take_section_errors() {
+        context
+            .report()
+            .record(lang.id(), pokemon_id, Some(section), &error);
+    }
+
+    render_to_write(
+        context,
+        "pokemon",
+        &pokemon,
+        base_path,
+        &relative_path,
+        lang,
+    )
+    .await?;
 
-    render_to_write(context.hb(), "pokemon", &pokemon, &path).await?;
+    if context.json() {
+        let data_path = PathBuf::from(format!("pokemons/{}.json", pokemon_id));
+        write_json(&pokemon, base_path, &data_path, lang)?;
+    }
 
-    Ok((pokemon_id.clone(), relative_path))
+    GeneratedPokemon::build(pokemon_id, &relative_path, context.rc(), lang).await
 }
@@ -1,20 +1,28 @@
-use std::path::PathBuf;
+use std::path::Path;
 
 use super::render_to_write;
 use crate::{
-    builders::{search::Search, Builder},
+    builders::{all_pokemon::GeneratedPokemon, search::Search, Builder},
     context::Context,
+    find_by_lang::Lang,
 };
 
 use anyhow::Result;
 
 pub(super) async fn generate_search_page(
-    mut path: PathBuf,
-    pokemon_id_and_names_and_paths: &Vec<(String, PathBuf)>,
+    base_path: &Path,
+    generated_pokemons: &Vec<GeneratedPokemon>,
+    lang: &Lang,
     context: &Context<'_>,
 ) -> Result<()> {
-    let search =
-        &Search::build(pokemon_id_and_names_and_paths, context.rc(), context.lang()).await?;
-    path.push("search.html");
-    render_to_write(context.hb(), "search", search, &path).await
+    let search = &Search::build(generated_pokemons, context.rc(), lang).await?;
+    render_to_write(
+        context,
+        "search",
+        search,
+        base_path,
+        Path::new("search.html"),
+        lang,
+    )
+    .await
 }
@@ -0,0 +1,258 @@
+use std::{collections::HashSet, iter, ops::RangeInclusive};
+
+use anyhow::{bail, Context, Result};
+use futures::{stream, StreamExt, TryStreamExt};
+use rustemon::{client::RustemonClient, Follow};
+
+use crate::args::Selection;
+
+/// Lists the names of the Pokemons matching `selection`.
+pub(super) async fn select_pokemon_names(
+    selection: &Selection,
+    jobs: usize,
+    rc: &RustemonClient,
+) -> Result<Vec<String>> {
+    let generation_names = get_generation_names(&selection.generations, rc).await?;
+
+    if !selection.names.is_empty() {
+        let known_names: HashSet<_> = list_all_pokemon_names(false, jobs, rc)
+            .await?
+            .into_iter()
+            .collect();
+        let unknown_names = unknown_pokemon_names(&selection.names, &known_names);
+        if !unknown_names.is_empty() {
+            bail!("Unknown Pokemon {}", unknown_names.join(", "));
+        }
+    }
+
+    let candidates = if !selection.names.is_empty() || !selection.generations.is_empty() {
+        let pokemon_names = if !selection.names.is_empty() {
+            selection.names.clone()
+        } else {
+            list_generations_pokemon_names(&selection.generations, jobs, rc).await?
+        };
+
+        if selection.default_forms_only {
+            pokemon_names
+        } else {
+            add_varieties(pokemon_names, jobs, rc).await?
+        }
+    } else {
+        list_all_pokemon_names(selection.default_forms_only, jobs, rc).await?
+    };
+
+    let filter = PokemonFilter {
+        range: selection.range.as_ref(),
+        generation_names: &generation_names,
+        types: &selection.types,
+    };
+    if filter.is_empty() {
+        return Ok(candidates);
+    }
+
+    stream::iter(candidates)
+        .map(|pokemon_name| {
+            let filter = &filter;
+            async move {
+                let is_selected = filter.matches(&pokemon_name, rc).await?;
+                Ok::<_, anyhow::Error>(is_selected.then_some(pokemon_name))
+            }
+        })
+        .buffered(jobs)
+        .try_filter_map(|pokemon_name| async move { Ok(pokemon_name) })
+        .try_collect()
+        .await
+}
+
+/// Returns the names of `names` that aren't in `known_names`.
+fn unknown_pokemon_names<'a>(names: &'a [String], known_names: &HashSet<String>) -> Vec<&'a str> {
+    names
+        .iter()
+        .filter(|name| !known_names.contains(*name))
+        .map(String::as_str)
+        .collect()
+}
+
+struct PokemonFilter<'a> {
+    range: Option<&'a RangeInclusive<i64>>,
+    generation_names: &'a [String],
+    types: &'a [String],
+}
+
+impl PokemonFilter<'_> {
+    fn is_empty(&self) -> bool {
+        self.range.is_none() && self.generation_names.is_empty() && self.types.is_empty()
+    }
+
+    async fn matches(&self, pokemon_name: &str, rc: &RustemonClient) -> Result<bool> {
+        let pokemon = rustemon::pokemon::pokemon::get_by_name(pokemon_name, rc)
+            .await
+            .with_context(|| format!("Unknown Pokemon {}", pokemon_name))?;
+
+        if !self.types.is_empty()
+            && !pokemon
+                .types
+                .iter()
+                .any(|pokemon_type| self.types.contains(&pokemon_type.type_.name))
+        {
+            return Ok(false);
+        }
+
+        if self.range.is_none() && self.generation_names.is_empty() {
+            return Ok(true);
+        }
+
+        let pokemon_species = pokemon.species.follow(rc).await?;
+
+        Ok(self
+            .range
+            .is_none_or(|range| range.contains(&pokemon_species.id))
+            && (self.generation_names.is_empty()
+                || self
+                    .generation_names
+                    .contains(&pokemon_species.generation.name)))
+    }
+}
+
+async fn get_generation_names(generations: &[i64], rc: &RustemonClient) -> Result<Vec<String>> {
+    let mut generation_names = Vec::with_capacity(generations.len());
+
+    for generation in generations {
+        let generation_name = rustemon::games::generation::get_by_id(*generation, rc)
+            .await
+            .with_context(|| format!("Unknown generation {}", generation))?
+            .name;
+        generation_names.push(generation_name);
+    }
+
+    Ok(generation_names)
+}
+
+async fn list_generations_pokemon_names(
+    generations: &[i64],
+    jobs: usize,
+    rc: &RustemonClient,
+) -> Result<Vec<String>> {
+    let mut pokemon_species = Vec::new();
+    for generation in generations {
+        pokemon_species.extend(
+            rustemon::games::generation::get_by_id(*generation, rc)
+                .await?
+                .pokemon_species,
+        );
+    }
+
+    let mut species_ids_and_pokemon_names: Vec<_> = stream::iter(pokemon_species)
+        .map(|pokemon_specie| async move {
+            let pokemon_specie = pokemon_specie.follow(rc).await?;
+            let pokemon_name = pokemon_specie
+                .varieties
+                .into_iter()
+                .find(|variety| variety.is_default)
+                .map(|variety| variety.pokemon.name)
+                .with_context(|| format!("No default variety for {}", pokemon_specie.name))?;
+            Ok::<_, anyhow::Error>((pokemon_specie.id, pokemon_name))
+        })
+        .buffered(jobs)
+        .try_collect()
+        .await?;
+    species_ids_and_pokemon_names.sort_by_key(|(species_id, _)| *species_id);
+
+    Ok(species_ids_and_pokemon_names
+        .into_iter()
+        .map(|(_, pokemon_name)| pokemon_name)
+        .collect())
+}
+
+/// Adds the other varieties of each Pokemon species right after the Pokemons in `pokemon_names`.
+async fn add_varieties(
+    pokemon_names: Vec<String>,
+    jobs: usize,
+    rc: &RustemonClient,
+) -> Result<Vec<String>> {
+    let varieties_names: Vec<Vec<String>> = stream::iter(&pokemon_names)
+        .map(|pokemon_name| async move {
+            let pokemon_specie = rustemon::pokemon::pokemon::get_by_name(pokemon_name, rc)
+                .await
+                .with_context(|| format!("Unknown Pokemon {}", pokemon_name))?
+                .species
+                .follow(rc)
+                .await?;
+            Ok::<_, anyhow::Error>(
+                pokemon_specie
+                    .varieties
+                    .into_iter()
+                    .map(|variety| variety.pokemon.name)
+                    .collect(),
+            )
+        })
+        .buffered(jobs)
+        .try_collect()
+        .await?;
+
+    let mut seen_pokemon_names = HashSet::new();
+    Ok(pokemon_names
+        .into_iter()
+        .zip(varieties_names)
+        .flat_map(|(pokemon_name, variety_names)| iter::once(pokemon_name).chain(variety_names))
+        .filter(|pokemon_name| seen_pokemon_names.insert(pokemon_name.clone()))
+        .collect())
+}
+
+async fn list_all_pokemon_names(
+    default_forms_only: bool,
+    jobs: usize,
+    rc: &RustemonClient,
+) -> Result<Vec<String>> {
+    let nb_pokemon = rustemon::pokemon::pokemon::get_page(rc).await?.count;
+
+    let mut pokemon_names = Vec::with_capacity(nb_pokemon as usize);
+
+    let mut offset = 0;
+    while offset < nb_pokemon {
+        let page = rustemon::pokemon::pokemon::get_page_with_param(offset, 100, rc).await?;
+
+        if !default_forms_only {
+            pokemon_names.extend(page.results.into_iter().map(|p| p.name));
+            offset += 100;
+            continue;
+        }
+
+        let default_pokemon_names: Vec<_> = stream::iter(page.results)
+            .map(|p| async move {
+                let is_default = p.follow(rc).await?.is_default;
+                Ok::<_, anyhow::Error>(is_default.then_some(p.name))
+            })
+            .buffered(jobs)
+            .try_filter_map(|name| async move { Ok(name) })
+            .try_collect()
+            .await?;
+        pokemon_names.extend(default_pokemon_names);
+        offset += 100;
+    }
+
+    Ok(pokemon_names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_pokemon_names_are_reported() {
+        let known_names: HashSet<_> = ["bulbasaur", "pikachu", "mr-mime"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let names: Vec<_> = ["pikachu", "pikachuu", "mr-mime", "Bulbasaur"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(
+            unknown_pokemon_names(&names, &known_names),
+            ["pikachuu", "Bulbasaur"]
+        );
+        assert!(unknown_pokemon_names(&names[..1], &known_names).is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    builders::{
+        all_pokemon::GeneratedPokemon,
+        type_chart::TypeChart,
+        type_detail::{self, TypeDetail},
+        Builder,
+    },
+    context::Context,
+    find_by_lang::Lang,
+};
+
+use super::{generate_element_pages, render_to_write, write_json};
+
+/// Generates a page for every type.
+pub(super) async fn generate_type_pages(
+    base_path: &Path,
+    generated_pokemons: &[GeneratedPokemon],
+    lang: &Lang,
+    jobs: usize,
+    context: &Context<'_>,
+) -> Result<()> {
+    let type_members = type_detail::collect_type_members(generated_pokemons, context.rc()).await?;
+
+    generate_element_pages::<_, TypeDetail>(
+        "types",
+        "type_detail",
+        &type_members,
+        base_path,
+        lang,
+        jobs,
+        context,
+    )
+    .await;
+
+    Ok(())
+}
+
+pub(super) async fn generate_type_chart_page(
+    base_path: &Path,
+    lang: &Lang,
+    context: &Context<'_>,
+) -> Result<()> {
+    let type_chart = &TypeChart::build(&(), context.rc(), lang).await?;
+
+    if context.json() {
+        write_json(type_chart, base_path, Path::new("type_chart.json"), lang)?;
+    }
+
+    render_to_write(
+        context,
+        "type_chart",
+        type_chart,
+        base_path,
+        Path::new("type_chart.html"),
+        lang,
+    )
+    .await
+}
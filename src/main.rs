@@ -13,9 +13,12 @@ use warp::Filter;
 
 mod args;
 mod builders;
+mod cache;
 mod context;
 mod find_by_lang;
 mod generators;
+mod manifest;
+mod report;
 mod utils;
 
 const ASSETS: include_dir::Dir = include_dir::include_dir!("./assets");
@@ -48,36 +51,74 @@ fn inner_export_assets(path: &Path, entries: &[DirEntry]) -> Result<()> {
     Ok(())
 }
 
-async fn run(base_path: PathBuf) {
-    let route = warp::path("rustedex").and(warp::fs::dir(base_path));
+async fn run(base_path: PathBuf, base_url_segments: Vec<String>) {
+    let prefix = base_url_segments
+        .into_iter()
+        .fold(warp::any().boxed(), |prefix, segment| {
+            prefix.and(warp::path(segment)).boxed()
+        });
+    let route = prefix.and(warp::fs::dir(base_path));
 
     warp::serve(route).run(([0, 0, 0, 0], 3030)).await;
 }
 
+/// Returns the path segments the server is mounted at, from the path of `base_url`.
+/// Relative links are served from the root.
+fn server_path_segments(base_url: &str) -> Vec<String> {
+    let Some(base_url) = context::normalize_base_url(base_url) else {
+        return vec![];
+    };
+    let base_url_path = base_url
+        .split_once("://")
+        .map_or(base_url.as_str(), |(_, url)| {
+            url.find('/').map_or("", |path_start| &url[path_start..])
+        });
+
+    base_url_path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = args::Args::parse();
 
-    println!(
-        "{}",
-        match remove_dir_all(&args.path) {
-            Ok(_) => "Clean successful",
-            Err(_) => "Nothing to clean",
-        }
-    );
-    DirBuilder::new().create(&args.path)?;
-    DirBuilder::new().create(args.path.join("pokemons"))?;
+    if !args.incremental {
+        println!(
+            "{}",
+            match remove_dir_all(&args.path) {
+                Ok(_) => "Clean successful",
+                Err(_) => "Nothing to clean",
+            }
+        );
+    }
+    DirBuilder::new().recursive(true).create(&args.path)?;
     export_assets(&args.path)?;
 
     if args.generate {
-        let context = context::Context::try_new()?;
-        generators::generate(args.path.clone(), &context).await?;
+        let context = context::Context::try_new(&args)?;
+        generators::generate(
+            args.path.clone(),
+            args.jobs.into(),
+            &args.selection,
+            &context,
+        )
+        .await?;
         println!("Static file generated at {}", args.path.display());
     }
 
     if args.serve {
-        println!("Starting server at http://localhost:3030/rustedex/home.html");
-        run(args.path).await;
+        let base_url_segments = server_path_segments(&args.base_url);
+        println!(
+            "Starting server at http://localhost:3030/{}home.html",
+            base_url_segments
+                .iter()
+                .map(|segment| format!("{}/", segment))
+                .collect::<String>()
+        );
+        run(args.path, base_url_segments).await;
     }
 
     Ok(())
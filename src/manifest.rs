@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Keeps track of the hash of every generated page's inputs,
+/// so that pages whose inputs didn't change since the previous run aren't rendered again.
+pub(crate) struct Manifest {
+    previous_hashes: BTreeMap<PathBuf, String>,
+    current_hashes: Mutex<BTreeMap<PathBuf, String>>,
+    template_hashes: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Loads the manifest left in `base_path` by the previous run, if any.
+    /// `templates` are the sources of the templates used in this run, by name.
+    pub fn load(base_path: &Path, templates: &BTreeMap<String, String>) -> Result<Self> {
+        let manifest_path = base_path.join(MANIFEST_FILE_NAME);
+        let previous_hashes = if manifest_path.exists() {
+            serde_json::from_reader(File::open(&manifest_path)?)
+                .with_context(|| format!("Invalid manifest {}", manifest_path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+
+        let template_hashes = templates
+            .iter()
+            .map(|(template_name, template_source)| {
+                (
+                    template_name.clone(),
+                    hex::encode(Sha256::digest(template_source.as_bytes())),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            previous_hashes,
+            current_hashes: Mutex::new(BTreeMap::new()),
+            template_hashes,
+        })
+    }
+
+    /// Computes the hash of a page rendered from `inner_template` with `data`.
+    /// The base layout and every partial are part of the hash, as any page may include them.
+    pub fn page_hash<T: Serialize>(&self, inner_template: &str, data: &T) -> Result<String> {
+        let mut hasher = Sha256::new();
+
+        for (template_name, template_hash) in &self.template_hashes {
+            if template_name == "base"
+                || template_name == inner_template
+                || template_name.starts_with("partials/")
+            {
+                hasher.update(template_name);
+                hasher.update(template_hash);
+            }
+        }
+        hasher.update(serde_json::to_vec(data)?);
+
+        Ok(hex::encode(hasher.finalize()))
+    }
+
+    /// Records `page_hash` for the page at `page_path`,
+    /// and tells if it was already generated with the same hash.
+    pub fn record(&self, page_path: &Path, page_hash: String) -> bool {
+        let is_up_to_date = self.previous_hashes.get(page_path) == Some(&page_hash);
+        self.current_hashes
+            .lock()
+            .unwrap()
+            .insert(page_path.to_owned(), page_hash);
+
+        is_up_to_date
+    }
+
+    /// Writes the hashes recorded during this run to `base_path`, merged into the previous ones
+    /// so that pages left out of this run, by the selection or a failure, stay up to date.
+    pub fn save(&self, base_path: &Path) -> Result<()> {
+        let mut hashes = self.previous_hashes.clone();
+        hashes.extend(
+            self.current_hashes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(page_path, page_hash)| (page_path.clone(), page_hash.clone())),
+        );
+
+        let file = File::create(base_path.join(MANIFEST_FILE_NAME))?;
+        serde_json::to_writer_pretty(file, &hashes)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs, path::PathBuf};
+
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct PageData {
+        name: &'static str,
+        values: BTreeMap<String, i64>,
+    }
+
+    fn page_data(keys: &[&str]) -> PageData {
+        PageData {
+            name: "bulbasaur",
+            values: keys
+                .iter()
+                .map(|key| (key.to_string(), key.len() as i64))
+                .collect(),
+        }
+    }
+
+    fn templates(pokemon_source: &str) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("base".to_owned(), "{{> (inner_template)}}".to_owned()),
+            ("pokemon".to_owned(), pokemon_source.to_owned()),
+            ("move".to_owned(), "{{name}}".to_owned()),
+        ])
+    }
+
+    /// Returns an empty directory dedicated to the test `name`.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rustedex-manifest-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn page_hash_is_stable() {
+        let manifest = Manifest::load(&test_dir("stable"), &templates("{{name}}")).unwrap();
+
+        let first = manifest
+            .page_hash("pokemon", &page_data(&["hp", "attack", "speed"]))
+            .unwrap();
+        let second = manifest
+            .page_hash("pokemon", &page_data(&["speed", "hp", "attack"]))
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn page_hash_covers_data_and_templates() {
+        let dir = test_dir("covers");
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        let data = page_data(&["hp"]);
+        let hash = manifest.page_hash("pokemon", &data).unwrap();
+
+        assert_ne!(
+            hash,
+            manifest
+                .page_hash("pokemon", &page_data(&["attack"]))
+                .unwrap()
+        );
+
+        let changed_manifest = Manifest::load(&dir, &templates("<b>{{name}}</b>")).unwrap();
+        assert_ne!(hash, changed_manifest.page_hash("pokemon", &data).unwrap());
+
+        // Only the templates the page is rendered with are part of its hash.
+        let mut other_templates = templates("{{name}}");
+        other_templates.insert("move".to_owned(), "<i>{{name}}</i>".to_owned());
+        let other_manifest = Manifest::load(&dir, &other_templates).unwrap();
+        assert_eq!(hash, other_manifest.page_hash("pokemon", &data).unwrap());
+    }
+
+    #[test]
+    fn record_tells_if_page_is_up_to_date() {
+        let dir = test_dir("record");
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        assert!(!manifest.record(Path::new("en/pokemons/bulbasaur.html"), "a".to_owned()));
+        manifest.save(&dir).unwrap();
+
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        assert!(manifest.record(Path::new("en/pokemons/bulbasaur.html"), "a".to_owned()));
+        assert!(!manifest.record(Path::new("en/pokemons/ivysaur.html"), "a".to_owned()));
+        assert!(!manifest.record(Path::new("en/pokemons/bulbasaur.html"), "b".to_owned()));
+    }
+
+    #[test]
+    fn save_keeps_pages_left_out_of_the_run() {
+        let dir = test_dir("merge");
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        manifest.record(Path::new("en/pokemons/bulbasaur.html"), "a".to_owned());
+        manifest.record(Path::new("en/pokemons/ivysaur.html"), "b".to_owned());
+        manifest.save(&dir).unwrap();
+
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        manifest.record(Path::new("en/pokemons/ivysaur.html"), "c".to_owned());
+        manifest.save(&dir).unwrap();
+
+        let manifest = Manifest::load(&dir, &templates("{{name}}")).unwrap();
+        assert!(manifest.record(Path::new("en/pokemons/bulbasaur.html"), "a".to_owned()));
+        assert!(manifest.record(Path::new("en/pokemons/ivysaur.html"), "c".to_owned()));
+    }
+}
@@ -0,0 +1,64 @@
+use std::{fs::File, io::Write, path::Path, sync::Mutex};
+
+use anyhow::Result;
+use serde::Serialize;
+
+const REPORT_FILE_NAME: &str = "report";
+
+/// A failure that prevented a page, or a section of it, from being generated.
+#[derive(Serialize)]
+pub(crate) struct Failure {
+    lang: String,
+    page: String,
+    section: Option<String>,
+    error: String,
+}
+
+/// Collects the failures of a run, so that a failing Pokemon doesn't abort the whole generation.
+#[derive(Default)]
+pub(crate) struct Report {
+    failures: Mutex<Vec<Failure>>,
+}
+
+impl Report {
+    /// Records `error` for `page` in `lang`, in `section` if only this section failed.
+    /// Pokemon pages are identified by the Pokemon name.
+    pub fn record(&self, lang: &str, page: &str, section: Option<&str>, error: &anyhow::Error) {
+        self.failures.lock().unwrap().push(Failure {
+            lang: lang.to_owned(),
+            page: page.to_owned(),
+            section: section.map(str::to_owned),
+            error: format!("{:#}", error),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.failures.lock().unwrap().len()
+    }
+
+    /// Writes the failures as text and JSON to `base_path`.
+    pub fn save(&self, base_path: &Path) -> Result<()> {
+        let failures = self.failures.lock().unwrap();
+
+        let mut text_report = File::create(base_path.join(format!("{}.txt", REPORT_FILE_NAME)))?;
+        for failure in failures.iter() {
+            writeln!(
+                text_report,
+                "[{}] {}{}: {}",
+                failure.lang,
+                failure.page,
+                failure
+                    .section
+                    .as_ref()
+                    .map(|section| format!(" ({})", section))
+                    .unwrap_or_default(),
+                failure.error
+            )?;
+        }
+
+        let json_report = File::create(base_path.join(format!("{}.json", REPORT_FILE_NAME)))?;
+        serde_json::to_writer_pretty(json_report, &*failures)?;
+
+        Ok(())
+    }
+}
@@ -1,41 +1,62 @@
-use std::{collections::HashMap, hash::Hash};
+use std::collections::BTreeMap;
 
 use anyhow::{Context, Result};
 use rustemon::{
     client::RustemonClient,
     model::{
-        pokemon::{EggGroup, PokemonAbility, PokemonStat},
-        resource::NamedApiResource,
+        pokemon::{EggGroup, Pokemon, PokemonSpecies, PokemonStat},
+        resource::{Name, NamedApiResource},
     },
     Follow,
 };
+use serde::de::DeserializeOwned;
 
-use crate::find_by_lang::FindWordingByLang;
+use crate::find_by_lang::{FindWordingByLang, Lang};
 
-pub(crate) async fn get_abilities_names_by_lang(
-    abilities: Vec<PokemonAbility>,
-    lang: &str,
+/// Returns the name of `pokemon` in `lang`, including its form for varieties such as Alolan Raichu.
+pub(crate) async fn get_pokemon_name_by_lang(
+    pokemon: &Pokemon,
+    pokemon_specie: &PokemonSpecies,
+    lang: &Lang,
     rc: &RustemonClient,
-) -> Result<Vec<String>> {
-    let mut result = Vec::with_capacity(abilities.len());
+) -> Result<String> {
+    let specie_name = pokemon_specie
+        .names
+        .find_by_lang(lang)
+        .with_context(|| format!("No {} name for {}", lang, pokemon_specie.name))?;
 
-    for ability in abilities {
-        let ability_name = ability
-            .ability
-            .follow(rc)
-            .await?
-            .names
+    let form = match pokemon.forms.first() {
+        Some(form) if !pokemon.is_default => form.follow(rc).await?,
+        _ => return Ok(specie_name),
+    };
+
+    Ok(form.names.find_by_lang(lang).unwrap_or_else(|| {
+        let form_name = form
+            .form_names
             .find_by_lang(lang)
-            .unwrap();
-        result.push(ability_name);
-    }
+            .unwrap_or_else(|| pokemon.name.clone());
+        format!("{} ({})", specie_name, form_name)
+    }))
+}
 
-    Ok(result)
+/// Returns the name of `resource` in `lang`, or its identifier if it has no such name.
+pub(crate) async fn get_name_by_lang<T>(
+    resource: &NamedApiResource<T>,
+    names: impl FnOnce(T) -> Vec<Name>,
+    rc: &RustemonClient,
+    lang: &Lang,
+) -> Result<String>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    Ok(names(resource.follow(rc).await?)
+        .find_by_lang(lang)
+        .unwrap_or_else(|| resource.name.clone()))
 }
 
 pub(crate) async fn get_egg_groups_names_by_lang(
     egg_groups: Vec<NamedApiResource<EggGroup>>,
-    lang: &str,
+    lang: &Lang,
     rc: &RustemonClient,
 ) -> Result<Vec<String>> {
     let mut result = Vec::with_capacity(egg_groups.len());
@@ -55,10 +76,10 @@ pub(crate) async fn get_egg_groups_names_by_lang(
 
 pub(crate) async fn get_effort_points_map_by_lang(
     pokemon_stats: Vec<PokemonStat>,
-    lang: &str,
+    lang: &Lang,
     rc: &RustemonClient,
-) -> Result<HashMap<String, i64>> {
-    let mut result = HashMap::new();
+) -> Result<BTreeMap<String, i64>> {
+    let mut result = BTreeMap::new();
 
     for pokemon_stat in pokemon_stats {
         if pokemon_stat.effort > 0 {
@@ -76,15 +97,17 @@ pub(crate) async fn get_effort_points_map_by_lang(
     Ok(result)
 }
 
-pub(crate) fn fuse_maps_in_place<K, V>(first: &mut HashMap<K, Vec<V>>, second: HashMap<K, Vec<V>>)
+pub(crate) fn fuse_maps_in_place<K, V>(first: &mut BTreeMap<K, Vec<V>>, second: BTreeMap<K, Vec<V>>)
 where
-    K: Eq + Hash,
+    K: Ord,
 {
     second
         .into_iter()
-        .for_each(|(k, v)| first.entry(k).or_insert_with(Vec::new).extend(v));
+        .for_each(|(k, v)| first.entry(k).or_default().extend(v));
 }
 
+/// Returns the version groups of `to_retain` with their display name, in PokeAPI order.
+/// Version groups missing from this list are left out, so it must follow PokeAPI's.
 pub(crate) fn get_version_group_id_and_names(
     to_retain: Vec<&String>,
 ) -> Vec<(&'static str, &'static str)> {
@@ -119,12 +142,54 @@ pub(crate) fn get_version_group_id_and_names(
             "Brilliant Diamond & Shining Pearl",
         ),
         ("legends-arceus", "Legends Arceus"),
+        ("scarlet-violet", "Scarlet & Violet"),
+        ("the-teal-mask", "The Teal Mask"),
+        ("the-indigo-disk", "The Indigo Disk"),
+        ("red-green-japan", "Red & Green (Japan)"),
+        ("blue-japan", "Blue (Japan)"),
     ])
     .into_iter()
     .filter(|&elem| to_retain.contains(&&elem.0.to_string()))
     .collect()
 }
 
+/// Returns the flavor texts of `entries` with their version group name, ordered by version group.
+/// They are taken in the first language of `lang` having any, line breaks being flattened.
+pub(crate) fn get_flavor_texts_by_version_group<T>(
+    entries: &[T],
+    language_version_group_and_text: impl Fn(&T) -> (&str, &str, &str),
+    lang: &Lang,
+) -> Vec<(&'static str, String)> {
+    let flavor_text_by_version_group: BTreeMap<String, String> = lang
+        .ids()
+        .iter()
+        .map(|lang_id| {
+            entries
+                .iter()
+                .map(&language_version_group_and_text)
+                .filter(|(language, _, _)| language == lang_id)
+                .map(|(_, version_group, flavor_text)| {
+                    (
+                        version_group.to_owned(),
+                        flavor_text.replace(['\n', '\u{000c}'], " "),
+                    )
+                })
+                .collect::<BTreeMap<_, _>>()
+        })
+        .find(|flavor_text_by_version_group| !flavor_text_by_version_group.is_empty())
+        .unwrap_or_default();
+
+    get_version_group_id_and_names(flavor_text_by_version_group.keys().collect())
+        .into_iter()
+        .map(|(version_group_id, version_group_name)| {
+            (
+                version_group_name,
+                flavor_text_by_version_group[version_group_id].clone(),
+            )
+        })
+        .collect()
+}
+
 pub(crate) fn get_type_ids() -> Vec<String> {
     vec![
         "normal".to_string(),